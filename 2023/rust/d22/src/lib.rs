@@ -1,5 +1,5 @@
-use std::collections::HashSet;
-
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
 use fxhash::FxHashSet;
 use nom::{
     IResult, Parser,
@@ -10,10 +10,12 @@ use nom::{
     sequence::terminated,
 };
 
+mod visualize;
+
 const BOTTOM_Z: i32 = 1;
 
 #[derive(Debug, Clone)]
-struct Brick {
+pub struct Brick {
     x1: i32,
     y1: i32,
     z1: i32,
@@ -23,14 +25,26 @@ struct Brick {
     z2: i32,
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_, bricks) = parse_bricks(input).unwrap();
-    let (bricks, _) = sim(bricks);
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse_bricks(input))
+    }
+
+    fn part1(bricks: &Self::Input<'_>) -> Result<Answer> {
+        let (bricks, _) = sim(bricks.clone());
+        let (p1, _) = solve(&bricks);
+        Ok(p1.into())
+    }
 
-    let (p1, p2) = solve(&bricks);
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+    fn part2(bricks: &Self::Input<'_>) -> Result<Answer> {
+        let (bricks, _) = sim(bricks.clone());
+        let (_, p2) = solve(&bricks);
+        Ok(p2.into())
+    }
 }
 
 fn sim(bricks: Vec<Brick>) -> (Vec<Brick>, u64) {
@@ -55,8 +69,8 @@ fn sim(bricks: Vec<Brick>) -> (Vec<Brick>, u64) {
             }
             moved = true;
             fallen += 1;
-            (*brick).z2 = min_z + (brick.z2 - brick.z1);
-            (*brick).z1 = min_z;
+            brick.z2 = min_z + (brick.z2 - brick.z1);
+            brick.z1 = min_z;
             positions.iter().for_each(|p| {
                 hs.remove(p);
             });
@@ -67,14 +81,14 @@ fn sim(bricks: Vec<Brick>) -> (Vec<Brick>, u64) {
             });
         }
     }
-    return (bricks, fallen);
+    (bricks, fallen)
 }
 
-fn solve(bricks: &Vec<Brick>) -> (u64, u64) {
+fn solve(bricks: &[Brick]) -> (u64, u64) {
     let mut stable = 0;
     let mut fallen_total = 0;
     for (index, _) in bricks.iter().enumerate() {
-        let mut test_bricks = bricks.clone();
+        let mut test_bricks = bricks.to_vec();
         test_bricks.remove(index);
         let (_, _fallen) = sim(test_bricks);
         fallen_total += _fallen;
@@ -82,7 +96,7 @@ fn solve(bricks: &Vec<Brick>) -> (u64, u64) {
             stable += 1;
         }
     }
-    return (stable, fallen_total);
+    (stable, fallen_total)
 }
 
 fn ray_down(pos: (i32, i32, i32), hs: &FxHashSet<(i32, i32, i32)>) -> i32 {
@@ -93,7 +107,7 @@ fn ray_down(pos: (i32, i32, i32), hs: &FxHashSet<(i32, i32, i32)>) -> i32 {
             return z + 1;
         }
     }
-    return BOTTOM_Z;
+    BOTTOM_Z
 }
 
 fn get_positions(brick: &Brick) -> Vec<(i32, i32, i32)> {
@@ -105,17 +119,17 @@ fn get_positions(brick: &Brick) -> Vec<(i32, i32, i32)> {
             }
         }
     }
-    return positions;
+    positions
 }
 
-fn bricks_to_hs(bricks: &Vec<Brick>) -> FxHashSet<(i32, i32, i32)> {
+fn bricks_to_hs(bricks: &[Brick]) -> FxHashSet<(i32, i32, i32)> {
     let mut set = FxHashSet::default();
     bricks.iter().for_each(|b| {
         get_positions(b).iter().for_each(|p| {
             set.insert(*p);
         });
     });
-    return set;
+    set
 }
 
 fn brick(input: &str) -> IResult<&str, Brick> {
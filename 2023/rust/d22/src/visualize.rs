@@ -0,0 +1,118 @@
+use aoc_core::Result;
+use aoc_vis::{Frame, Player, Render, Visualize};
+use fxhash::{FxHashMap, FxHashSet};
+
+use super::{BOTTOM_Z, Brick, Day22, bricks_to_hs, get_positions};
+
+/// Columns between the two views.
+const GAP: i32 = 3;
+
+/// The bricks after `ticks` rounds of every unsupported brick dropping one
+/// level.
+struct Stack {
+    bricks: Vec<Brick>,
+    top: i32,
+    ticks: usize,
+    falling: usize,
+}
+
+impl Render for Stack {
+    /// The stack seen from the front, along y, next to the stack seen from
+    /// the side, along x, as in the puzzle. Each cell shows the nearest
+    /// brick.
+    fn render(&self) -> Frame {
+        let max_x = self.bricks.iter().map(|b| b.x2).max().unwrap_or(0);
+        let max_y = self.bricks.iter().map(|b| b.y2).max().unwrap_or(0);
+        let side = max_x + 1 + GAP;
+        let mut front: FxHashMap<(i32, i32), (i32, usize)> = FxHashMap::default();
+        let mut end: FxHashMap<(i32, i32), (i32, usize)> = FxHashMap::default();
+        for (i, brick) in self.bricks.iter().enumerate() {
+            for (x, y, z) in get_positions(brick) {
+                let nearest = front.entry((x, z)).or_insert((y, i));
+                if y < nearest.0 {
+                    *nearest = (y, i);
+                }
+                let nearest = end.entry((y, z)).or_insert((x, i));
+                if x < nearest.0 {
+                    *nearest = (x, i);
+                }
+            }
+        }
+
+        // Level `z` is drawn on row `top - z`, with the ground below level 1.
+        let mut frame = Frame::new((side + max_y + 1) as usize, self.top as usize + 1)
+            .with_caption(format!(
+                "tick {}, {} bricks dropped a level",
+                self.ticks, self.falling
+            ))
+            .keep_bottom();
+        for (&(x, z), &(_, i)) in &front {
+            frame.set((x, self.top - z), label(i), Some(i));
+        }
+        for (&(y, z), &(_, i)) in &end {
+            frame.set((side + y, self.top - z), label(i), Some(i));
+        }
+        let ground = self.top - BOTTOM_Z + 1;
+        for x in (0..=max_x).chain(side..=side + max_y) {
+            frame.set((x, ground), '-', None);
+        }
+        frame
+    }
+}
+
+/// Bricks are lettered like in the puzzle, starting over after Z.
+fn label(i: usize) -> char {
+    (b'A' + (i % 26) as u8) as char
+}
+
+/// Drops every brick with nothing beneath it by one level, lowest first so
+/// that a falling pile falls together. Returns how many bricks moved.
+fn tick(bricks: &mut [Brick], occupied: &mut FxHashSet<(i32, i32, i32)>) -> usize {
+    // Sorted indices rather than the bricks themselves, so that each brick
+    // keeps its label.
+    let mut order: Vec<usize> = (0..bricks.len()).collect();
+    order.sort_by_key(|&i| bricks[i].z1);
+    let mut falling = 0;
+    for i in order {
+        let brick = &mut bricks[i];
+        let positions = get_positions(brick);
+        let supported = brick.z1 == BOTTOM_Z
+            || positions
+                .iter()
+                .filter(|(_, _, z)| *z == brick.z1)
+                .any(|&(x, y, z)| occupied.contains(&(x, y, z - 1)));
+        if supported {
+            continue;
+        }
+        falling += 1;
+        positions.iter().for_each(|p| {
+            occupied.remove(p);
+        });
+        brick.z1 -= 1;
+        brick.z2 -= 1;
+        get_positions(brick).into_iter().for_each(|p| {
+            occupied.insert(p);
+        });
+    }
+    falling
+}
+
+impl Visualize for Day22 {
+    fn visualize(bricks: &Self::Input<'_>, player: &mut Player) -> Result<()> {
+        let mut stack = Stack {
+            bricks: bricks.clone(),
+            top: bricks.iter().map(|b| b.z2).max().unwrap_or(BOTTOM_Z),
+            ticks: 0,
+            falling: 0,
+        };
+        let mut occupied = bricks_to_hs(&stack.bricks);
+        while player.show(&stack) {
+            stack.falling = tick(&mut stack.bricks, &mut occupied);
+            if stack.falling == 0 {
+                break;
+            }
+            stack.ticks += 1;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_core::{Answer, AocError, Result, Solution};
+use aoc_grid::{Grid, Pos, adjacent, step};
+use fxhash::FxHashSet;
+
+type Graph = Grid<Tile>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Tile {
+    Wall,
+    Forest,
+    Slope(Dir),
+}
+
+impl Dir {
+    fn delta(self) -> Pos {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+            Dir::Right => (1, 0),
+        }
+    }
+}
+
+impl Tile {
+    fn walkable(&self) -> bool {
+        match self {
+            Tile::Wall => false,
+            Tile::Forest | Tile::Slope(_) => true,
+        }
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = (Graph, Pos, Pos);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((graph, start, goal): &Self::Input<'_>) -> Result<Answer> {
+        Ok(longest_path_dfs(graph, start, goal, true).into())
+    }
+
+    fn part2((graph, start, goal): &Self::Input<'_>) -> Result<Answer> {
+        Ok(longest_path_dfs(graph, start, goal, false).into())
+    }
+}
+
+fn adjacent_positions2(pos: &Pos) -> Vec<Pos> {
+    adjacent(*pos).collect()
+}
+
+fn adjacent_positions(pos: &Pos, graph: &Graph) -> Vec<Pos> {
+    match graph[*pos] {
+        Tile::Wall | Tile::Forest => adjacent(*pos).collect(),
+        Tile::Slope(dir) => vec![step(*pos, dir.delta())],
+    }
+}
+
+fn longest_path_dfs(graph: &Graph, start: &Pos, goal: &Pos, p1: bool) -> i32 {
+    let mut max_length = 0;
+    let mut visited = FxHashSet::default();
+    fn dfs(
+        pos: &Pos,
+        curr_len: i32,
+        visited: &mut FxHashSet<Pos>,
+        max_length: &mut i32,
+        goal: &Pos,
+        graph: &Graph,
+        p1: bool,
+    ) {
+        if pos == goal {
+            if curr_len > *max_length {
+                *max_length = curr_len;
+            }
+            return;
+        }
+        let adj = {
+            if p1 {
+                adjacent_positions(pos, graph)
+            } else {
+                adjacent_positions2(pos)
+            }
+        };
+        let adj = adj
+            .iter()
+            .filter(|adj_pos| graph.get(**adj_pos).is_some_and(|tile| tile.walkable()));
+        for adj_pos in adj {
+            if visited.contains(adj_pos) {
+                continue;
+            }
+            visited.insert(*adj_pos);
+            dfs(adj_pos, curr_len + 1, visited, max_length, goal, graph, p1);
+            visited.remove(adj_pos);
+        }
+    }
+
+    dfs(start, 0, &mut visited, &mut max_length, goal, graph, p1);
+    max_length
+}
+
+fn parse(input: &str) -> Result<(Graph, Pos, Pos)> {
+    let graph = Grid::try_parse(input, |ch| match ch {
+        '#' => Some(Tile::Wall),
+        '.' => Some(Tile::Forest),
+        '^' => Some(Tile::Slope(Dir::Up)),
+        'v' => Some(Tile::Slope(Dir::Down)),
+        '<' => Some(Tile::Slope(Dir::Left)),
+        '>' => Some(Tile::Slope(Dir::Right)),
+        _ => None,
+    })?;
+    let gap_in_row = |y: i32| {
+        (0..graph.width() as i32)
+            .map(|x| (x, y))
+            .find(|&pos| graph[pos] == Tile::Forest)
+            .ok_or_else(|| AocError::Parse {
+                line: y.max(0) as usize + 1,
+                column: 1,
+                message: "expected a gap in the wall".to_string(),
+            })
+    };
+    let start = gap_in_row(0)?;
+    let goal = gap_in_row(graph.height() as i32 - 1)?;
+    Ok((graph, start, goal))
+}
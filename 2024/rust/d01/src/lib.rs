@@ -1,17 +1,31 @@
 use nom::branch::alt;
 use nom::character::complete::{line_ending, space0, u32};
 use nom::multi::fold_many0;
-use nom::{combinator::eof, IResult};
+use nom::{combinator::eof, IResult, Parser};
 use std::collections::HashMap;
 
-fn main() {
-    part1();
-    part2();
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1((v1, v2): &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1(v1.clone(), v2.clone()).into())
+    }
+
+    fn part2((v1, v2): &Self::Input<'_>) -> Result<Answer> {
+        Ok(part2(v1, v2.clone()).into())
+    }
 }
 
-fn part1() {
-    let content = include_str!("input.txt");
-    let (_, (mut v1, mut v2)) = parse(content).unwrap();
+fn part1(mut v1: Vec<u32>, mut v2: Vec<u32>) -> u32 {
     v1.sort();
     v2.sort();
 
@@ -19,21 +33,18 @@ fn part1() {
     for i in 0..v1.len() {
         ctr += v1[i].abs_diff(v2[i]);
     }
-
-    println!("{}", ctr);
+    ctr
 }
 
-fn part2() {
-    let content = include_str!("input.txt");
-    let (_, (v1, v2)) = parse(content).unwrap();
+fn part2(v1: &[u32], v2: Vec<u32>) -> u32 {
     let map = frequency_map(v2);
 
     let mut ctr = 0;
     for i in v1 {
-        let counter = map.get(&i).unwrap_or(&0);
+        let counter = map.get(i).unwrap_or(&0);
         ctr += counter * i;
     }
-    println!("{}", ctr);
+    ctr
 }
 
 fn frequency_map(input: Vec<u32>) -> HashMap<u32, u32> {
@@ -54,7 +65,7 @@ fn parse(input: &str) -> IResult<&str, (Vec<u32>, Vec<u32>)> {
             acc2.push(n2);
             (acc1, acc2)
         },
-    )(input)?;
+    ).parse(input)?;
 
     Ok((input, (v1, v2)))
 }
@@ -63,6 +74,6 @@ fn line(input: &str) -> IResult<&str, (u32, u32)> {
     let (input, n1) = u32(input)?;
     let (input, _) = space0(input)?;
     let (input, n2) = u32(input)?;
-    let (input, _) = alt((line_ending, eof))(input)?;
+    let (input, _) = alt((line_ending, eof)).parse(input)?;
     Ok((input, (n1, n2)))
 }
@@ -1,26 +1,36 @@
 use nom::character::complete::{space1, u32};
 use nom::multi::separated_list1;
-use nom::{character::complete::line_ending, IResult};
+use nom::{character::complete::line_ending, IResult, Parser};
 
-fn main() {
-    let contents = include_str!("input.txt");
-    let (_, data) = parse(contents).unwrap();
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
 
-    let p1 = data.iter().filter(|x| is_safe(x)).count();
-    let p2 = data.iter().filter(|x| can_remove1(x)).count();
+pub struct Day02;
 
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+impl Solution for Day02 {
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1(data: &Self::Input<'_>) -> Result<Answer> {
+        Ok(data.iter().filter(|x| is_safe(x)).count().into())
+    }
+
+    fn part2(data: &Self::Input<'_>) -> Result<Answer> {
+        Ok(data.iter().filter(|x| can_remove1(x)).count().into())
+    }
 }
 
 fn parse(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
-    separated_list1(line_ending, separated_list1(space1, u32))(input)
+    separated_list1(line_ending, separated_list1(space1, u32)).parse(input)
 }
 
 fn can_remove1(report: &[u32]) -> bool {
     if is_safe(report) {
         return true;
-    } 
+    }
     for i in 0..report.len() {
         let mut new_report = report.to_owned();
         new_report.remove(i);
@@ -36,7 +46,7 @@ fn is_safe(report: &[u32]) -> bool {
     for x in report.windows(2) {
         if (inc && (x[0] >= x[1] || x[1] - x[0] > 3)) || (!inc && (x[1] >= x[0] || x[0] - x[1] > 3)) {
             return false;
-        } 
+        }
     }
     true
 }
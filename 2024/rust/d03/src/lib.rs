@@ -8,14 +8,41 @@ use nom::{
 };
 
 use nom::combinator::map;
-use std::time::Instant;
+
+use aoc_core::{Answer, AocError, Result, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(content: &Self::Input<'_>) -> Result<Answer> {
+        let (_, p1) = p1(content).map_err(|_| no_instructions())?;
+        Ok(p1.into())
+    }
+
+    fn part2(content: &Self::Input<'_>) -> Result<Answer> {
+        let (_, p2) = p2(content).map_err(|_| no_instructions())?;
+        Ok(p2.into())
+    }
+}
+
+/// Anything around the instructions is corruption, so the only way to fail is
+/// to find none at all.
+fn no_instructions() -> AocError {
+    AocError::no_solution("the memory holds no instructions")
+}
 
 fn mul(input: &str) -> IResult<&str, (i32, i32)> {
-    delimited(tag("mul("), separated_pair(i32, tag(","), i32), tag(")"))(input)
+    delimited(tag("mul("), separated_pair(i32, tag(","), i32), tag(")")).parse(input)
 }
 
 fn p1(input: &str) -> IResult<&str, i32> {
-    let (input, v) = many1(many_till(anychar, mul).map(|(_, (a, b))| a * b))(input)?;
+    let (input, v) = many1(many_till(anychar, mul).map(|(_, (a, b))| a * b)).parse(input)?;
     Ok((input, v.iter().sum()))
 }
 #[derive(Debug)]
@@ -35,7 +62,7 @@ fn p2(input: &str) -> IResult<&str, i32> {
             )),
         )
         .map(|(_, v)| v),
-    )(input)?;
+    ).parse(input)?;
     let (_, acc): (i32, i32) = ins.iter().fold((1, 0), |(proc, acc), op| match op {
         Op::Mul(a, b) => (proc, acc + a * b * proc),
         Op::Do => (1, acc),
@@ -43,13 +70,3 @@ fn p2(input: &str) -> IResult<&str, i32> {
     });
     Ok((input, acc))
 }
-
-fn main() {
-    let content = include_str!("input.txt");
-    let time = Instant::now();
-    let (_, p1) = p1(content).unwrap();
-    let (_, p2) = p2(content).unwrap();
-    println!("Time: {}µs", time.elapsed().as_micros());
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
-}
@@ -1,15 +1,26 @@
+use aoc_core::{Answer, AocError, Result, Solution};
+
 const W_SIZE: usize = 4;
 const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
 const MAS: [char; 3] = ['M', 'A', 'S'];
 const SAM: [char; 3] = ['S', 'A', 'M'];
 
-fn main() {
-    let content = include_str!("input.txt");
-    let time = std::time::Instant::now();
-    let map = parse(content);
-    let p1 = p1(&map);
-    let p2 = p2(&map);
-    println!("P1: {}\nP2: {}\nTime: {:?}", p1, p2, time.elapsed());
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(map).into())
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(map).into())
+    }
 }
 
 #[derive(Debug)]
@@ -24,8 +35,21 @@ enum Dir {
     DownRight,
 }
 
-fn parse(content: &str) -> Vec<Vec<char>> {
-    content.lines().map(|line| line.chars().collect()).collect()
+fn parse(content: &str) -> Result<Vec<Vec<char>>> {
+    let map: Vec<Vec<char>> = content.lines().map(|line| line.chars().collect()).collect();
+    let Some(width) = map.first().map(Vec::len).filter(|&width| width > 0) else {
+        return Err(AocError::at_end(content, "the word search is empty"));
+    };
+    for (line, row) in content.lines().zip(&map) {
+        if row.len() != width {
+            return Err(AocError::at(
+                content,
+                line,
+                format!("row is {} wide, expected {}", row.len(), width),
+            ));
+        }
+    }
+    Ok(map)
 }
 
 fn p1(map: &[Vec<char>]) -> u32 {
@@ -156,3 +180,19 @@ fn calc_pos_p1(map: &[Vec<char>], x: usize, y: usize) -> u32 {
     }
     acc
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../fixtures/2024/04/example.txt");
+
+    #[test]
+    fn rejects_truncated_input() {
+        assert!(matches!(
+            Day04::parse(&EXAMPLE[..36]),
+            Err(AocError::Parse { line: 4, .. })
+        ));
+        assert!(Day04::parse("").is_err());
+    }
+}
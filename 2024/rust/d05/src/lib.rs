@@ -1,6 +1,9 @@
 use std::cmp::Ordering;
 use std::collections::HashSet;
 
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
+
 use nom::bytes::complete::tag;
 use nom::character::complete::{line_ending, u32};
 use nom::multi::separated_list1;
@@ -10,15 +13,22 @@ use nom::{sequence::separated_pair, IResult};
 
 type Relation = HashSet<(u32, u32)>;
 
-fn main() {
-    let content = include_str!("input.txt");
-    let time = std::time::Instant::now();
-    let (_, (re, updates)) = parse(content).unwrap();
-    let p1 = p1(&re, &updates);
-    let p2 = p2(&re, &updates);
-    println!("Time: {}µs", time.elapsed().as_micros());
-    println!("{}", p1);
-    println!("{}", p2);
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = (Relation, Vec<Vec<u32>>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1((re, updates): &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(re, updates).into())
+    }
+
+    fn part2((re, updates): &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(re, updates).into())
+    }
 }
 
 fn p2(re: &Relation, updates: &[Vec<u32>]) -> u32 {
@@ -39,7 +49,7 @@ fn p2(re: &Relation, updates: &[Vec<u32>]) -> u32 {
 fn cmp(relation: &Relation, a: u32, b: u32) -> Ordering {
     match relation.contains(&(a, b)) {
         true => Ordering::Less,
-        false => Ordering::Greater 
+        false => Ordering::Greater
     }
 }
 
@@ -60,13 +70,13 @@ fn parse(input: &str) -> IResult<&str, (Relation, Vec<Vec<u32>>)> {
         separated_list1(line_ending, rule).map(|rules| rules.into_iter().collect()),
         pair(line_ending, line_ending),
         separated_list1(line_ending, update),
-    )(input)
+    ).parse(input)
 }
 
 fn update(input: &str) -> IResult<&str, Vec<u32>> {
-    separated_list1(tag(","), u32)(input)
+    separated_list1(tag(","), u32).parse(input)
 }
 
 fn rule(input: &str) -> IResult<&str, (u32, u32)> {
-    separated_pair(u32, tag("|"), u32)(input)
-}
\ No newline at end of file
+    separated_pair(u32, tag("|"), u32).parse(input)
+}
@@ -0,0 +1,116 @@
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use aoc_core::error::finish;
+use aoc_core::{Answer, AocError, Result, Solution};
+use aoc_grid::{Grid, Pos, DIRS};
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::{value, verify};
+use nom::multi::{many1, separated_list1};
+use nom::{IResult, Parser};
+
+mod visualize;
+
+pub type Map = Grid<Obj>;
+type Dir = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Obj {
+    Empty,
+    Wall,
+    Padding,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = (Map, Pos);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let map = finish(input, map(input))?;
+        Ok((map, get_start(input)?))
+    }
+
+    fn part1((map, pos): &Self::Input<'_>) -> Result<Answer> {
+        let (path, _) = sim(map, *pos, 0);
+        Ok(path.keys().count().into())
+    }
+
+    fn part2((map, pos): &Self::Input<'_>) -> Result<Answer> {
+        let (path, _) = sim(map, *pos, 0);
+        Ok(num_loops(map, *pos, &path).into())
+    }
+}
+
+fn num_loops(map: &Map, pos: Pos, original_path: &HashMap<Pos, Vec<Dir>>) -> i64 {
+    original_path
+        .par_iter()
+        .filter_map(|((x, y), dirs)| {
+            if (*x, *y) == pos {
+                return None;
+            }
+            let mut local_map = map.clone();
+            local_map[(*x, *y)] = Obj::Wall;
+            let dir = dirs[0];
+            let (dx, dy) = DIRS[dir];
+            let start_pos = (*x - dx, *y - dy);
+            let (_, r#loop) = sim(&local_map, start_pos, dir);
+            Some(r#loop as i64)
+        })
+        .sum()
+}
+
+fn sim(map: &Map, pos: Pos, dir: usize) -> (HashMap<Pos, Vec<Dir>>, bool) {
+    let mut visited: HashMap<Pos, Vec<Dir>> = HashMap::new();
+    let mut pos = pos;
+    let mut dir = dir;
+    while !visited.get(&pos).map(|d| d.contains(&dir)).unwrap_or(false) {
+        visited.entry(pos).or_default().push(dir);
+        match step(map, pos, dir) {
+            Some(next) => (pos, dir) = next,
+            None => return (visited, false),
+        }
+    }
+    (visited, true)
+}
+
+/// Where the guard is and faces after their next move, or `None` if it takes
+/// them off the map.
+fn step(map: &Map, pos: Pos, dir: Dir) -> Option<(Pos, Dir)> {
+    let (dx, dy) = DIRS[dir];
+    let next_pos = (pos.0 + dx, pos.1 + dy);
+    match map[next_pos] {
+        Obj::Wall => Some((pos, (dir + 1) % 4)),
+        Obj::Empty => Some((next_pos, dir)),
+        Obj::Padding => None,
+    }
+}
+
+fn get_start(input: &str) -> Result<Pos> {
+    let (x, y) = Grid::try_parse(input, Some)?
+        .find(|&c| c == '^')
+        .ok_or_else(|| AocError::at_end(input, "the map has no guard"))?;
+    // Shifted onto the padded map.
+    Ok((x + 1, y + 1))
+}
+
+fn obj(input: &str) -> IResult<&str, Obj> {
+    use Obj::*;
+    alt((
+        value(Empty, tag(".")),
+        value(Empty, tag("^")),
+        value(Wall, tag("#")),
+    )).parse(input)
+}
+
+fn map(input: &str) -> IResult<&str, Map> {
+    let (input, map) = verify(
+        separated_list1(line_ending, many1(obj)),
+        |rows: &Vec<Vec<Obj>>| rows.iter().all(|row| row.len() == rows[0].len()),
+    )
+    .parse(input)?;
+    Ok((input, Grid::from_rows(map).padded(Obj::Padding)))
+}
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use aoc_core::Result;
+use aoc_grid::Pos;
+use aoc_vis::{Frame, Player, Render, Visualize};
+
+use super::{Day06, Dir, Map, Obj, step};
+
+const GUARD: [char; 4] = ['^', '>', 'v', '<'];
+
+/// The guard partway through their patrol.
+struct Patrol<'a> {
+    map: &'a Map,
+    pos: Pos,
+    dir: Dir,
+    visited: HashSet<Pos>,
+    steps: usize,
+}
+
+impl Render for Patrol<'_> {
+    fn render(&self) -> Frame {
+        // The padding around the map is shifted out of the frame.
+        let shift = |(x, y): Pos| (x - 1, y - 1);
+        let mut frame =
+            Frame::new(self.map.width() - 2, self.map.height() - 2).with_caption(format!(
+                "step {}, {} positions visited",
+                self.steps,
+                self.visited.len()
+            ));
+        for (pos, obj) in self.map.iter() {
+            match obj {
+                Obj::Wall => frame.set(shift(pos), '#', None),
+                Obj::Empty => frame.set(shift(pos), '.', None),
+                Obj::Padding => {}
+            }
+        }
+        for &pos in &self.visited {
+            frame.set(shift(pos), 'X', Some(3));
+        }
+        frame.set(shift(self.pos), GUARD[self.dir], Some(0));
+        frame
+    }
+}
+
+impl Visualize for Day06 {
+    fn visualize((map, start): &Self::Input<'_>, player: &mut Player) -> Result<()> {
+        let mut patrol = Patrol {
+            map,
+            pos: *start,
+            dir: 0,
+            visited: HashSet::from([*start]),
+            steps: 0,
+        };
+        // Stops when the guard leaves the map or starts going round in a loop.
+        let mut seen = HashSet::from([(*start, 0)]);
+        while player.show(&patrol) {
+            let Some((pos, dir)) = step(map, patrol.pos, patrol.dir) else {
+                break;
+            };
+            if !seen.insert((pos, dir)) {
+                break;
+            }
+            patrol.pos = pos;
+            patrol.dir = dir;
+            patrol.visited.insert(pos);
+            patrol.steps += 1;
+        }
+        Ok(())
+    }
+}
@@ -1,39 +1,54 @@
 use rayon::prelude::*;
-use std::vec;
+
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
 
 use nom::bytes::complete::tag;
 use nom::{character::complete::space1, multi::separated_list1, IResult};
 type Line = (u64, Vec<u64>);
 use nom::character::complete::{line_ending, u64};
+use nom::Parser;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
 
-fn main() {
-    let cont = include_str!("input.txt");
-    let (_, lines) = parse(cont).unwrap();
+    fn part1(lines: &Self::Input<'_>) -> Result<Answer> {
+        Ok(lines
+            .iter()
+            .filter(|l| possible_p1(l))
+            .map(|l| l.0)
+            .sum::<u64>()
+            .into())
+    }
 
-    let res1: u64 = lines
-        .clone()
-        .into_iter()
-        .filter(possible_p1)
-        .map(|l| l.0)
-        .sum();
-    println!("Part 1: {}", res1);
-    let res: u64 = lines.into_par_iter().filter(possible_p2).map(|l| l.0).sum();
-    println!("Part 2: {}", res);
+    fn part2(lines: &Self::Input<'_>) -> Result<Answer> {
+        Ok(lines
+            .par_iter()
+            .filter(|l| possible_p2(l))
+            .map(|l| l.0)
+            .sum::<u64>()
+            .into())
+    }
 }
 
 fn line(input: &str) -> IResult<&str, Line> {
     let (input, number) = u64(input)?;
-    let (input, _) = tag(":")(input)?;
+    let (input, _) = tag(":").parse(input)?;
     let (input, _) = space1(input)?;
-    let (input, numbers) = separated_list1(space1, u64)(input)?;
+    let (input, numbers) = separated_list1(space1, u64).parse(input)?;
     Ok((input, (number, numbers)))
 }
 
 fn parse(input: &str) -> IResult<&str, Vec<Line>> {
-    separated_list1(line_ending, line)(input)
+    separated_list1(line_ending, line).parse(input)
 }
 
-
 fn possible_p2((goal, nums): &Line) -> bool {
     let n = (nums.len() - 1) as u64;
     for i in 0..3u64.pow(n as u32) {
@@ -46,7 +61,7 @@ fn possible_p2((goal, nums): &Line) -> bool {
                     let mut s = res.to_string();
                     s.push_str(&nums[(j + 1) as usize].to_string());
                     s.parse().unwrap()
-                },
+                }
                 _ => unreachable!(),
             };
             if res > *goal {
@@ -1,17 +1,27 @@
-use std::{any, collections::HashMap};
+use std::collections::HashMap;
+
+use aoc_core::{Answer, Result, Solution};
 
 type Pos = (i32, i32);
 type Antennas = HashMap<char, Vec<Pos>>;
 type AntiNodes = HashMap<Pos, Vec<char>>;
 
-fn main() {
-    println!("Hello, world!");
-    let content = include_str!("input.txt");
-    let (antennas, xbound, ybound) = parse_input(content);
-    let res1 = unique_antinodes_p2(&antennas, xbound, ybound);
-    let res2 = unique_antinodes_p1(&antennas, xbound, ybound);
-    println!("Result 1: {}", res1);
-    println!("Result 2: {}", res2);
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = (Antennas, usize, usize);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1((antennas, xbound, ybound): &Self::Input<'_>) -> Result<Answer> {
+        Ok(unique_antinodes_p1(antennas, *xbound, *ybound).into())
+    }
+
+    fn part2((antennas, xbound, ybound): &Self::Input<'_>) -> Result<Answer> {
+        Ok(unique_antinodes_p2(antennas, *xbound, *ybound).into())
+    }
 }
 
 fn unique_antinodes_p1(antennas: &Antennas, xbound: usize, ybound: usize) -> usize {
@@ -139,7 +149,7 @@ fn parse_input(input: &str) -> (Antennas, usize, usize) {
     }
     (
         antennas,
-        input.lines().next().unwrap().len(),
+        input.lines().next().map_or(0, str::len),
         input.lines().count(),
     )
 }
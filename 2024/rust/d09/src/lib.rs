@@ -1,19 +1,27 @@
+use aoc_core::{Answer, AocError, Result, Solution};
 
-fn main() {
-    let content = include_str!("input.txt");
-    let blocks = parse(content);
-    let p1_blocks = defrag_unstable(&blocks);
-    let c1 = block_checksum(&p1_blocks);
+pub struct Day09;
 
-    let p2_blocks = defrag_stable(&blocks);
-    let c2 = block_checksum(&p2_blocks);
+impl Solution for Day09 {
+    type Input<'a> = Vec<Block>;
 
-    println!("Part 1: {}", c1);
-    println!("Part 2: {}", c2);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(blocks: &Self::Input<'_>) -> Result<Answer> {
+        let p1_blocks = defrag_unstable(blocks);
+        Ok(block_checksum(&p1_blocks).into())
+    }
+
+    fn part2(blocks: &Self::Input<'_>) -> Result<Answer> {
+        let p2_blocks = defrag_stable(blocks);
+        Ok(block_checksum(&p2_blocks).into())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Block {
+pub enum Block {
     File(usize),
     Empty,
 }
@@ -112,22 +120,62 @@ fn defrag_stable(blocks: &[Block]) -> Vec<Block> {
 }
 
 
-fn parse(input: &str) -> Vec<Block> {
+fn parse(input: &str) -> Result<Vec<Block>> {
     let mut blocks = Vec::new();
-    let chars: Vec<char> = input.chars().collect();
-    let mut i = 0;
-    while i < chars.len() {
-        let f_blocks = chars[i].to_digit(10).unwrap();
-        blocks.extend(std::iter::repeat(Block::File(i / 2)).take(f_blocks as usize));
-        i += 1;
-        if i >= chars.len() {
-            break;
+    for (i, c) in input.trim_end().char_indices() {
+        let Some(len) = c.to_digit(10) else {
+            return Err(AocError::at(
+                input,
+                &input[i..],
+                format!("expected a digit, found {:?}", c),
+            ));
+        };
+        let block = if i % 2 == 0 {
+            Block::File(i / 2)
+        } else {
+            Block::Empty
+        };
+        if len == 0 && !block.is_empty() {
+            return Err(AocError::at(
+                input,
+                &input[i..],
+                format!("file {} has no blocks", i / 2),
+            ));
         }
-        let emp_blocks = chars[i].to_digit(10).unwrap();
-        blocks.extend(std::iter::repeat(Block::Empty).take(emp_blocks as usize));
-        i += 1;
+        blocks.extend(std::iter::repeat_n(block, len as usize));
+    }
+    if blocks.is_empty() {
+        return Err(AocError::at_end(input, "the disk map is empty"));
     }
-    blocks
+    Ok(blocks)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    const EXAMPLE: &str = include_str!("../../../../fixtures/2024/09/example.txt");
+
+    #[test]
+    fn malformed() {
+        let err = Day09::parse("2333x33\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected a digit, found 'x'"
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        assert_eq!(
+            Day09::parse(&EXAMPLE[..0]).unwrap_err().to_string(),
+            "line 1, column 1: the disk map is empty"
+        );
+        assert_eq!(
+            Day09::parse("02").unwrap_err().to_string(),
+            "line 1, column 1: file 0 has no blocks"
+        );
+        let input = Day09::parse(&EXAMPLE[..3]).unwrap();
+        assert_eq!(Day09::part1(&input).unwrap().to_string(), "9");
+    }
+}
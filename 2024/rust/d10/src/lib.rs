@@ -0,0 +1,73 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{Answer, Result, Solution};
+use aoc_grid::{Grid, Pos};
+
+type Map = Grid<Option<u32>>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = (Map, Vec<Pos>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((map, starts): &Self::Input<'_>) -> Result<Answer> {
+        Ok(starts
+            .iter()
+            .map(|start| paths_to_peak(map, *start, false))
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2((map, starts): &Self::Input<'_>) -> Result<Answer> {
+        Ok(starts
+            .iter()
+            .map(|start| paths_to_peak(map, *start, true))
+            .sum::<u32>()
+            .into())
+    }
+}
+
+
+fn paths_to_peak(map: &Map, start: Pos, distinct: bool) -> u32 {
+    let mut stack = VecDeque::new();
+    let mut visited: HashSet<Pos> = HashSet::new();
+    let mut ctr = 0;
+    stack.push_back((0, start));
+    while let Some((height, pos)) = stack.pop_front()
+    {
+        if !distinct && visited.contains(&pos) {
+            continue;
+        }
+        if height == 9 {
+            ctr += 1;
+            if !distinct {
+                visited.insert(pos);
+            }
+            continue;
+        }
+        for adj_pos in map.neighbours(pos) {
+            if map[adj_pos] != Some(height + 1) {
+                continue;
+            }
+            stack.push_back((height + 1, adj_pos));
+        }
+    }
+    ctr
+}
+
+fn parse(input: &str) -> Result<(Map, Vec<Pos>)> {
+    let map = Grid::try_parse(input, |c| match c {
+        '.' => Some(None),
+        _ => c.to_digit(10).map(Some),
+    })?;
+    let starts = map
+        .iter()
+        .filter(|&(_, &height)| height == Some(0))
+        .map(|(pos, _)| pos)
+        .collect();
+    Ok((map, starts))
+}
@@ -0,0 +1,64 @@
+use nom::character::complete::{space1, u64};
+use nom::multi::separated_list1;
+use nom::{IResult, Parser};
+
+use aoc_core::error::finish;
+use aoc_core::memo::Memo;
+use aoc_core::{Answer, Result, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Answer> {
+        Ok(blink_times(numbers, 25).into())
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Result<Answer> {
+        Ok(blink_times(numbers, 75).into())
+    }
+}
+
+/// How many stones a stone turns into, by its number and the blinks left.
+type Blinks = Memo<(u64, u64), u64>;
+
+fn blink_times(nums: &[u64], times: u64) -> u64 {
+    let mut memo = Blinks::new();
+    nums.iter().map(|&num| blink(num, times, &mut memo)).sum()
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<u64>> {
+    separated_list1(space1, u64).parse(input)
+}
+
+fn blink(number: u64, times: u64, memo: &mut Blinks) -> u64 {
+    if times == 0 {
+        return 1;
+    }
+    memo.get_or_compute(&(number, times), |memo| {
+        if number == 0 {
+            return blink(1, times - 1, memo);
+        }
+
+        let log10 = (number as f64).log10().floor() as u64;
+        if log10 % 2 == 1 {
+            let (big_half, small_half) = split_number(number);
+            return blink(big_half, times - 1, memo) + blink(small_half, times - 1, memo);
+        }
+
+        blink(number * 2024, times - 1, memo)
+    })
+}
+
+fn split_number(number: u64) -> (u64, u64) {
+    let log10 = (number as f64).log10().floor() as u64 + 1;
+    let half = 10u64.pow((log10 / 2) as u32);
+    let small_half = number % half;
+    let big_half = (number - small_half) / half;
+    (big_half, small_half)
+}
@@ -1,7 +1,9 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-type Pos = (i32, i32);
-type Garden = HashMap<Pos, char>;
+use aoc_core::{Answer, Result, Solution};
+use aoc_grid::{adjacent, Grid, Pos};
+
+type Garden = Grid<char>;
 type Perimiters = HashSet<(Pos, Pos, Dir)>;
 type Area = HashSet<Pos>;
 
@@ -13,23 +15,35 @@ enum Dir {
     Right,
 }
 
-fn main() {
-    let content = include_str!("input.txt");
-    let map = parse(content);
-    let (c1, c2) = cost(&map);
-    println!("Part 1: {}", c1);
-    println!("Part 2: {}", c2);
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::try_parse(input, Some)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Answer> {
+        let (c1, _) = cost(map);
+        Ok(c1.into())
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Answer> {
+        let (_, c2) = cost(map);
+        Ok(c2.into())
+    }
 }
 
 fn cost(map: &Garden) -> (usize, usize) {
     let mut visited = Area::new();
     let mut cost1 = 0;
     let mut cost2 = 0;
-    for pos in map.keys() {
-        if visited.contains(pos) {
+    for pos in map.positions() {
+        if visited.contains(&pos) {
             continue;
         }
-        let (area, perimiters) = bfs(map, pos);
+        let (area, perimiters) = bfs(map, &pos);
         let sides = find_comb_sides(&perimiters);
         visited.extend(area.iter());
         cost1 += area.len() * perimiters.len();
@@ -38,27 +52,17 @@ fn cost(map: &Garden) -> (usize, usize) {
     (cost1, cost2)
 }
 
-fn parse(input: &str) -> HashMap<Pos, char> {
-    let mut map = HashMap::new();
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            map.insert((x as i32, y as i32), c);
-        }
-    }
-    map
-}
-
 fn bfs(map: &Garden, start: &Pos) -> (Area, Perimiters) {
     let mut visited = Area::new();
     let mut perimiters = Perimiters::new();
-    let plant_type = map[start];
+    let plant_type = map[*start];
     let mut queue = vec![*start];
     while let Some(pos) = queue.pop() {
         if !visited.insert(pos) {
             continue;
         }
-        for next in adjacent(&pos) {
-            if map.get(&next) == Some(&plant_type) {
+        for next in adjacent(pos) {
+            if map.get(next) == Some(&plant_type) {
                 queue.push(next);
             } else {
                 let dir = if next.0 > pos.0 {
@@ -77,17 +81,7 @@ fn bfs(map: &Garden, start: &Pos) -> (Area, Perimiters) {
     (visited, perimiters)
 }
 
-fn adjacent(pos: &Pos) -> Vec<Pos> {
-    let (x, y) = pos;
-    vec![
-        (x - 1, *y),
-        (x + 1, *y),
-        (*x, y - 1),
-        (*x, y + 1),
-    ]
-}
-
-fn find_comb_sides(perimiters: &Perimiters) -> Vec<HashSet<(Pos, Pos)>>{
+fn find_comb_sides(perimiters: &Perimiters) -> Vec<HashSet<(Pos, Pos)>> {
     let mut sides: Vec<HashSet<(Pos, Pos)>> = vec![];
     for (a, b, dir) in perimiters.iter() {
         if sides.iter().any(|s| s.contains(&(*a, *b))) {
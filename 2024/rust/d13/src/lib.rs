@@ -1,13 +1,15 @@
 use nom::character::complete::{i64, line_ending};
 use nom::multi::separated_list1;
-use nom::sequence::tuple;
 
-use nom::{bytes::complete::tag, IResult};
+use nom::{bytes::complete::tag, IResult, Parser};
+
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
 
 const BONUS: i64 = 10e12 as i64;
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     ax: i64,
     ay: i64,
     bx: i64,
@@ -16,14 +18,22 @@ struct Game {
     py: i64,
 }
 
-fn main() {
-    let content = include_str!("input.txt");
-    let (_, games) = games(content).unwrap();
-    let bonus_games = bonus_games(&games);
-    let p1: i64 = games.iter().map(solve).sum();
-    let p2: i64 = bonus_games.iter().map(solve).sum();
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, games(input))
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Result<Answer> {
+        Ok(games.iter().map(solve).sum::<i64>().into())
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Result<Answer> {
+        Ok(bonus_games(games).iter().map(solve).sum::<i64>().into())
+    }
 }
 
 fn bonus_games(games: &[Game]) -> Vec<Game> {
@@ -52,22 +62,22 @@ fn solve(g: &Game) -> i64 {
 }
 
 fn games(input: &str) -> IResult<&str, Vec<Game>> {
-    separated_list1(tuple((line_ending, line_ending)), |input| {
-        let (input, _) = tag("Button A: X+")(input)?;
+    separated_list1((line_ending, line_ending), |input| {
+        let (input, _) = tag("Button A: X+").parse(input)?;
         let (input, ax) = i64(input)?;
-        let (input, _) = tag(", Y+")(input)?;
+        let (input, _) = tag(", Y+").parse(input)?;
         let (input, ay) = i64(input)?;
         let (input, _) = line_ending(input)?;
 
-        let (input, _) = tag("Button B: X+")(input)?;
+        let (input, _) = tag("Button B: X+").parse(input)?;
         let (input, bx) = i64(input)?;
-        let (input, _) = tag(", Y+")(input)?;
+        let (input, _) = tag(", Y+").parse(input)?;
         let (input, by) = i64(input)?;
         let (input, _) = line_ending(input)?;
 
-        let (input, _) = tag("Prize: X=")(input)?;
+        let (input, _) = tag("Prize: X=").parse(input)?;
         let (input, px) = i64(input)?;
-        let (input, _) = tag(", Y=")(input)?;
+        let (input, _) = tag(", Y=").parse(input)?;
         let (input, py) = i64(input)?;
 
         Ok((
@@ -81,5 +91,5 @@ fn games(input: &str) -> IResult<&str, Vec<Game>> {
                 py,
             },
         ))
-    })(input)
+    }).parse(input)
 }
@@ -0,0 +1,364 @@
+use std::ops::Range;
+
+use nom::character::complete::{i32, line_ending};
+use nom::multi::separated_list1;
+use nom::{bytes::complete::tag, IResult, Parser};
+
+use aoc_core::error::finish;
+use aoc_core::{Answer, AocError, Params, Result, Solution};
+use aoc_grid::Grid;
+use aoc_image::{Animation, Export, Image, Picture};
+
+mod visualize;
+
+const MAX_X: i32 = 101;
+const MAX_Y: i32 = 103;
+
+#[derive(Debug)]
+pub struct Robot {
+    p: (i32, i32),
+    v: (i32, i32),
+}
+
+impl Robot {
+    fn sim(&self, t: i32, m_x: i32, m_y: i32) -> (i32, i32) {
+        let x = (self.p.0 + t * self.v.0) % m_x;
+        let x = if x < 0 { x + m_x } else { x };
+        let y = (self.p.1 + t * self.v.1) % m_y;
+        let y = if y < 0 { y + m_y } else { y };
+        (x, y)
+    }
+}
+
+fn safety_factor(space: &Space) -> usize {
+    space.quadrants(100).iter().product()
+}
+
+/// How many of `positions` are in each quadrant, top left, top right,
+/// bottom left then bottom right. The middle row and column of an odd sized
+/// space are in none.
+fn quadrants(positions: &[(i32, i32)], m_x: i32, m_y: i32) -> [usize; 4] {
+    let half_w = m_x / 2;
+    let half_h = m_y / 2;
+    let mut counts = [0; 4];
+    for &(x, y) in positions {
+        if x == half_w && m_x % 2 == 1 || y == half_h && m_y % 2 == 1 {
+            continue;
+        }
+        let right = usize::from(x >= half_w);
+        let bottom = usize::from(y >= half_h);
+        counts[bottom * 2 + right] += 1;
+    }
+    counts
+}
+
+/// How far below the usual spread of the robots, in standard deviations,
+/// the spread has to drop for them to be drawing something.
+const OUTLIER: f64 = 4.0;
+
+/// The first second the robots bunch up into the tree.
+///
+/// Robots wander around independently in x and y, with x repeating every
+/// `m_x` seconds and y every `m_y`. The tree frame is the one second where
+/// they bunch up on both axes at once, so each axis is searched over one
+/// period for its least spread out second, and the two are combined with the
+/// Chinese remainder theorem.
+fn tree_time(robots: &[Robot], m_x: i32, m_y: i32) -> Option<i64> {
+    let t_x = tightest(m_x, |t| robots.iter().map(move |r| r.sim(t, m_x, m_y).0))?;
+    let t_y = tightest(m_y, |t| robots.iter().map(move |r| r.sim(t, m_x, m_y).1))?;
+    crt((t_x, m_x), (t_y, m_y))
+}
+
+/// The second within `period` at which `positions` are the most bunched up,
+/// if that stands out from every other second.
+fn tightest<I>(period: i32, positions: impl Fn(i32) -> I) -> Option<i32>
+where
+    I: Iterator<Item = i32>,
+{
+    let spreads: Vec<f64> = (0..period).map(|t| variance(positions(t))).collect();
+    let (t, &min) = spreads
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+    let mean = spreads.iter().sum::<f64>() / spreads.len() as f64;
+    let deviation = variance(spreads.iter().copied()).sqrt();
+    (min < mean - OUTLIER * deviation).then_some(t as i32)
+}
+
+fn variance<T: Into<f64>>(values: impl Iterator<Item = T>) -> f64 {
+    let (mut n, mut sum, mut squares) = (0.0, 0.0, 0.0);
+    for value in values {
+        let value = value.into();
+        n += 1.0;
+        sum += value;
+        squares += value * value;
+    }
+    if n == 0.0 {
+        return 0.0;
+    }
+    let mean = sum / n;
+    squares / n - mean * mean
+}
+
+/// The smallest `t` with `t % m == r` for both `(r, m)` pairs, if there is
+/// one.
+fn crt((r_a, m_a): (i32, i32), (r_b, m_b): (i32, i32)) -> Option<i64> {
+    let (m_a, m_b) = (i64::from(m_a), i64::from(m_b));
+    (0..m_b)
+        .map(|k| i64::from(r_a) + k * m_a)
+        .find(|t| t % m_b == i64::from(r_b))
+}
+
+/// The robots and the size of the space they move around in.
+pub struct Space {
+    robots: Vec<Robot>,
+    width: i32,
+    height: i32,
+}
+
+impl Space {
+    /// Where every robot is after `t` seconds.
+    pub fn positions(&self, t: i32) -> Vec<(i32, i32)> {
+        self.robots
+            .iter()
+            .map(|r| r.sim(t, self.width, self.height))
+            .collect()
+    }
+
+    /// How many robots are in each quadrant after `t` seconds, in the order
+    /// top left, top right, bottom left, bottom right.
+    pub fn quadrants(&self, t: i32) -> [usize; 4] {
+        quadrants(&self.positions(t), self.width, self.height)
+    }
+
+    /// How many robots are on each tile after `t` seconds.
+    pub fn to_grid(&self, t: i32) -> Grid<u32> {
+        self.heatmap(t..t + 1)
+    }
+
+    /// How many seconds out of `seconds` each tile had a robot on it for,
+    /// counting every robot on it.
+    pub fn heatmap(&self, seconds: Range<i32>) -> Grid<u32> {
+        let mut grid = Grid::new(self.width as usize, self.height as usize, 0);
+        for t in seconds {
+            for pos in self.positions(t) {
+                grid[pos] += 1;
+            }
+        }
+        grid
+    }
+
+    /// The number of seconds after which every robot is back where it
+    /// started, and the robots go round the same states again.
+    ///
+    /// Each axis repeats once every robot has wrapped round it a whole
+    /// number of times, which is at most the width or height but sooner if
+    /// the speeds share a factor with it.
+    pub fn period(&self) -> i32 {
+        let axis = |size: i32, speed: fn(&Robot) -> i32| {
+            let step = self
+                .robots
+                .iter()
+                .fold(size, |step, r| gcd(step, speed(r).rem_euclid(size)));
+            size / step
+        };
+        lcm(axis(self.width, |r| r.v.0), axis(self.height, |r| r.v.1))
+    }
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn lcm(a: i32, b: i32) -> i32 {
+    a / gcd(a, b) * b
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Space;
+
+    const PARAMS: &[&str] = &["width", "height"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        let width = params.get("width", MAX_X)?;
+        let height = params.get("height", MAX_Y)?;
+        if width <= 0 || height <= 0 {
+            return Err(AocError::Param(format!(
+                "the space cannot be {}x{}",
+                width, height
+            )));
+        }
+        Ok(Space {
+            robots: finish(input, parse_input(input))?,
+            width,
+            height,
+        })
+    }
+
+    fn part1(space: &Self::Input<'_>) -> Result<Answer> {
+        Ok(safety_factor(space).into())
+    }
+
+    // Can be checked by watching `aoc run 2024 14 --visualize`, or the GIF
+    // from `--export`, for the tree.
+    fn part2(space: &Self::Input<'_>) -> Result<Answer> {
+        tree_time(&space.robots, space.width, space.height)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::no_solution("the robots never form a picture"))
+    }
+}
+
+/// Hundredths of a second each second of the robots is shown for.
+const FRAME_DELAY: u16 = 2;
+
+impl Export for Day14 {
+    /// Every second of one full cycle of the robots, after which they are
+    /// all back where they started.
+    fn export<'a>(space: &'a Self::Input<'_>) -> Result<Picture<'a>> {
+        let frames = (0..space.period()).map(|t| {
+            let mut image = Image::new(space.width as usize, space.height as usize);
+            for pos in space.positions(t) {
+                image.set(pos, Some(1));
+            }
+            image
+        });
+        Ok(Picture::Animation(Animation::new(FRAME_DELAY, frames)))
+    }
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Robot>> {
+    separated_list1(line_ending, robot).parse(input)
+}
+
+fn robot(input: &str) -> IResult<&str, Robot> {
+    let (input, _) = tag("p=").parse(input)?;
+    let (input, px) = i32(input)?;
+    let (input, _) = tag(",").parse(input)?;
+    let (input, py) = i32(input)?;
+    let (input, _) = tag(" v=").parse(input)?;
+    let (input, vx) = i32(input)?;
+    let (input, _) = tag(",").parse(input)?;
+    let (input, vy) = i32(input)?;
+    Ok((
+        input,
+        Robot {
+            p: (px, py),
+            v: (vx, vy),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../fixtures/2024/14/example.txt");
+
+    // The example robots move around an 11 by 7 space and never form a
+    // tree.
+    #[test]
+    fn part2_without_a_tree() {
+        let params = Params::new().with("width", 11).with("height", 7);
+        let input = Day14::parse_with(EXAMPLE, &params).unwrap();
+        assert!(matches!(Day14::part2(&input), Err(AocError::NoSolution(_))));
+    }
+
+    /// Scattered robots with a third of them sent to fill a 31 by 33 box
+    /// at second 6_587, like the real input.
+    #[test]
+    fn part2_finds_the_box() {
+        const T: i32 = 6_587;
+        let mut rng = Rng::new(14);
+        let mut random = |n: i32| rng.below(n as u64) as i32;
+        let robots: Vec<_> = (0..500)
+            .map(|i| {
+                let v = (random(MAX_X) - MAX_X / 2, random(MAX_Y) - MAX_Y / 2);
+                let p = if i % 3 == 0 {
+                    let end = Robot {
+                        p: (35 + random(31), 40 + random(33)),
+                        v: (-v.0, -v.1),
+                    };
+                    end.sim(T, MAX_X, MAX_Y)
+                } else {
+                    (random(MAX_X), random(MAX_Y))
+                };
+                Robot { p, v }
+            })
+            .collect();
+        assert_eq!(tree_time(&robots, MAX_X, MAX_Y), Some(i64::from(T)));
+    }
+
+    #[test]
+    fn counts_quadrants() {
+        let params = Params::new().with("width", 11).with("height", 7);
+        let space = Day14::parse_with(EXAMPLE, &params).unwrap();
+        assert_eq!(space.quadrants(100), [1, 3, 4, 1]);
+        assert_eq!(space.to_grid(100).iter().map(|(_, &n)| n).sum::<u32>(), 12);
+        assert_eq!(
+            space.to_grid(100).to_string(),
+            "\
+00000020010
+00000000000
+10000000000
+01100000000
+00000100000
+00012000000
+01000010000"
+        );
+    }
+
+    #[test]
+    fn heats_up_tiles_over_time() {
+        let space = Space {
+            robots: vec![Robot {
+                p: (0, 0),
+                v: (1, 0),
+            }],
+            width: 3,
+            height: 2,
+        };
+        assert_eq!(space.heatmap(0..4).to_string(), "211\n000");
+    }
+
+    #[test]
+    fn repeats_after_the_period() {
+        let params = Params::new().with("width", 11).with("height", 7);
+        let space = Day14::parse_with(EXAMPLE, &params).unwrap();
+        assert_eq!(space.period(), 77);
+        assert_eq!(space.positions(0), space.positions(77));
+
+        // Both robots move an even number of tiles across a space 4 wide,
+        // so are back in x after 2 seconds rather than 4.
+        let space = Space {
+            robots: vec![
+                Robot {
+                    p: (0, 0),
+                    v: (2, 1),
+                },
+                Robot {
+                    p: (1, 2),
+                    v: (-2, 3),
+                },
+            ],
+            width: 4,
+            height: 3,
+        };
+        assert_eq!(space.period(), 6);
+        assert_eq!(space.positions(0), space.positions(6));
+    }
+
+    #[test]
+    fn combines_periods() {
+        assert_eq!(crt((2, 3), (3, 5)), Some(8));
+        assert_eq!(crt((1, 4), (2, 6)), None);
+        assert_eq!(crt((1, 4), (3, 6)), Some(9));
+    }
+}
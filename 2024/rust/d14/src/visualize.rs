@@ -0,0 +1,37 @@
+use aoc_core::Result;
+use aoc_vis::{Frame, Player, Render, Visualize};
+
+use super::{Day14, Space};
+
+/// The robots after `t` seconds.
+struct Moment<'a> {
+    space: &'a Space,
+    t: i32,
+}
+
+impl Render for Moment<'_> {
+    fn render(&self) -> Frame {
+        let [q_0, q_1, q_2, q_3] = self.space.quadrants(self.t);
+        Frame::from_grid(&self.space.to_grid(self.t), |&count| match count {
+            0 => ('.', None),
+            1..=9 => (char::from_digit(count, 10).unwrap(), Some(count as usize)),
+            _ => ('+', Some(0)),
+        })
+        .with_caption(format!(
+            "t = {}s, quadrants {} {} {} {}",
+            self.t, q_0, q_1, q_2, q_3
+        ))
+    }
+}
+
+impl Visualize for Day14 {
+    /// Plays one full cycle, until every robot is back where it started.
+    fn visualize(space: &Self::Input<'_>, player: &mut Player) -> Result<()> {
+        for t in 0..space.period() {
+            if !player.show(&Moment { space, t }) {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
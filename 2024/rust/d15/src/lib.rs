@@ -0,0 +1,23 @@
+use aoc_core::{Answer, Result, Solution};
+
+mod part1;
+mod part2;
+mod visualize;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = (part1::Warehouse, part2::Warehouse);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok((part1::parse(input)?, part2::parse(input)?))
+    }
+
+    fn part1((warehouse, _): &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1::solve(warehouse).into())
+    }
+
+    fn part2((_, warehouse): &Self::Input<'_>) -> Result<Answer> {
+        Ok(part2::solve(warehouse).into())
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_grid::{Grid, Pos, DIRS};
+
+mod parsing;
+pub(crate) use parsing::parse;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Obj {
+    Empty,
+    Wall,
+    Rock,
+}
+
+pub type Dir = usize;
+pub type Map = Grid<Obj>;
+pub type Warehouse = (Map, Pos, Vec<Dir>);
+
+pub(crate) fn solve((map, start, dirs): &Warehouse) -> i32 {
+    let map = walk(map.clone(), *start, dirs);
+    gps_sum(&map)
+}
+
+fn gps_sum(map: &Map) -> i32 {
+    map.iter().fold(0, |sum, ((x, y), obj)| match obj {
+        Obj::Rock => sum + x + y * 100,
+        _ => sum,
+    })
+}
+
+fn walk(map: Map, start: Pos, dirs: &[Dir]) -> Map {
+    let mut pos = start;
+    let mut map = map;
+    for &dir in dirs {
+        (map, pos) = step(map, pos, dir);
+    }
+    map
+}
+
+/// Moves the robot at `pos` one step towards `dir` if it can push whatever is
+/// in the way, returning the map and where the robot ends up.
+pub(crate) fn step(map: Map, pos: Pos, dir: Dir) -> (Map, Pos) {
+    let (dx, dy) = DIRS[dir];
+    let new_pos = (pos.0 + dx, pos.1 + dy);
+    match map.get(new_pos) {
+        Some(Obj::Empty) => (map, new_pos),
+        Some(Obj::Rock) => {
+            let (map, moved) = try_move(map, new_pos, dir);
+            (map, if moved { new_pos } else { pos })
+        }
+        Some(Obj::Wall) | None => (map, pos),
+    }
+}
+
+fn try_move(map: Map, pos: Pos, dir: Dir) -> (Map, bool) {
+    let (dx, dy) = DIRS[dir];
+    let mut new_pos = (pos.0 + dx, pos.1 + dy);
+    let mut map = map;
+    while let Some(obj) = map.get(new_pos) {
+        match obj {
+            Obj::Empty => {
+                map[new_pos] = Obj::Rock;
+                map[pos] = Obj::Empty;
+                return (map, true);
+            }
+            Obj::Rock => {
+                new_pos = (new_pos.0 + dx, new_pos.1 + dy);
+            }
+            Obj::Wall => {
+                return (map, false);
+            }
+        }
+    }
+    // Rocks pushed up against the edge of the map stop as if it were a wall.
+    (map, false)
+}
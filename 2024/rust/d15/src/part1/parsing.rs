@@ -0,0 +1,52 @@
+use aoc_core::{AocError, Result};
+
+use super::*;
+
+pub fn parse(input: &str) -> Result<Warehouse> {
+    let (map, dirs) = input.split_once("\n\n").ok_or_else(|| {
+        AocError::at_end(input, "expected a blank line between the map and the moves")
+    })?;
+    let start = parse_start(input, map)?;
+    let map = parse_map(map)?;
+    let dirs = parse_dirs(input, dirs)?;
+    Ok((map, start, dirs))
+}
+
+fn parse_dirs(input: &str, dirs: &str) -> Result<Vec<Dir>> {
+    let mut parsed = Vec::new();
+    for line in dirs.lines() {
+        for (i, c) in line.char_indices() {
+            let dir = match c {
+                '^' => 0,
+                '>' => 1,
+                'v' => 2,
+                '<' => 3,
+                _ => {
+                    return Err(AocError::at(
+                        input,
+                        &line[i..],
+                        format!("unknown direction {:?}", c),
+                    ));
+                }
+            };
+            parsed.push(dir);
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_map(input: &str) -> Result<Map> {
+    Grid::try_parse(input, |c| match c {
+        '.' => Some(Obj::Empty),
+        '@' => Some(Obj::Empty),
+        '#' => Some(Obj::Wall),
+        'O' => Some(Obj::Rock),
+        _ => None,
+    })
+}
+
+fn parse_start(input: &str, map: &str) -> Result<Pos> {
+    Grid::try_parse(map, Some)?
+        .find(|&c| c == '@')
+        .ok_or_else(|| AocError::at(input, &map[map.len()..], "the map has no robot"))
+}
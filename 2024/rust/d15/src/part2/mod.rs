@@ -1,99 +1,101 @@
 use core::panic;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+
+use aoc_grid::{Grid, Pos, DIRS};
 
 mod parsing;
-use parsing::parse;
+pub(crate) use parsing::parse;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Obj {
+pub enum Obj {
     Empty,
     Wall,
     RockRight,
     RockLeft,
 }
 
-// Up, Down, Left, Right
-const DIRS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
-type Dir = usize;
-type Pos = (i32, i32);
-type Map = HashMap<Pos, Obj>;
-
-fn main() {
-    let content = include_str!("input.txt");
-    let p2 = solve(content);
-    println!("Part 2: {}", p2);
-}
+pub type Dir = usize;
+pub type Map = Grid<Obj>;
+pub type Warehouse = (Map, Pos, Vec<Dir>);
 
-fn solve(content: &str) -> i32 {
-    let (map, start, dirs) = parse(content);
-    let map = walk(map, start, dirs);
+pub(crate) fn solve((map, start, dirs): &Warehouse) -> i32 {
+    let map = walk(map.clone(), *start, dirs);
     gps_sum(&map)
 }
 
 fn gps_sum(map: &Map) -> i32 {
-    map.iter().fold(0, |sum, (&(x, y), obj)| match obj {
+    map.iter().fold(0, |sum, ((x, y), obj)| match obj {
         Obj::RockLeft => sum + x + y * 100,
         _ => sum,
     })
 }
 
-fn walk(map: Map, start: Pos, dirs: Vec<Dir>) -> Map {
+fn walk(map: Map, start: Pos, dirs: &[Dir]) -> Map {
     let mut pos = start;
     let mut map = map;
-    for dir in dirs {
-        let (dx, dy) = DIRS[dir];
-        let new_pos = (pos.0 + dx, pos.1 + dy);
-        match map[&new_pos] {
-            Obj::Empty => pos = new_pos,
-            Obj::Wall => {}
-            Obj::RockLeft => {
-                if dy == 0 && can_move_hor(&map, new_pos, dx) {
-                    map = move_hor(map, new_pos, dx);
+    for &dir in dirs {
+        (map, pos) = step(map, pos, dir);
+    }
+    map
+}
+
+/// Moves the robot at `pos` one step towards `dir` if it can push whatever is
+/// in the way, returning the map and where the robot ends up.
+pub(crate) fn step(map: Map, pos: Pos, dir: Dir) -> (Map, Pos) {
+    let mut pos = pos;
+    let mut map = map;
+    let (dx, dy) = DIRS[dir];
+    let new_pos = (pos.0 + dx, pos.1 + dy);
+    match map[new_pos] {
+        Obj::Empty => pos = new_pos,
+        Obj::Wall => {}
+        Obj::RockLeft => {
+            if dy == 0 && can_move_hor(&map, new_pos, dx) {
+                map = move_hor(map, new_pos, dx);
+                pos = new_pos;
+            } else if dx == 0 {
+                let corr_pos = (new_pos.0 + 1, new_pos.1);
+                let positions = HashSet::from([new_pos, corr_pos]);
+                if can_move_vert(&map, &positions, dy) {
+                    map = move_vert(map, positions, dy);
                     pos = new_pos;
-                } else if dx == 0 {
-                    let corr_pos = (new_pos.0 + 1, new_pos.1);
-                    let positions = HashSet::from([new_pos, corr_pos]);
-                    if can_move_vert(&map, &positions, dy) {
-                        map = move_vert(map, positions, dy);
-                        pos = new_pos;
-                    }
                 }
             }
-            Obj::RockRight => {
-                if dy == 0 && can_move_hor(&map, new_pos, dx) {
-                    map = move_hor(map, new_pos, dx);
+        }
+        Obj::RockRight => {
+            if dy == 0 && can_move_hor(&map, new_pos, dx) {
+                map = move_hor(map, new_pos, dx);
+                pos = new_pos;
+            } else if dx == 0 {
+                let corr_pos = (new_pos.0 - 1, new_pos.1);
+                let positions = HashSet::from([new_pos, corr_pos]);
+                if can_move_vert(&map, &positions, dy) {
+                    map = move_vert(map, positions, dy);
                     pos = new_pos;
-                } else if dx == 0 {
-                    let corr_pos = (new_pos.0 - 1, new_pos.1);
-                    let positions = HashSet::from([new_pos, corr_pos]);
-                    if can_move_vert(&map, &positions, dy) {
-                        map = move_vert(map, positions, dy);
-                        pos = new_pos;
-                    }
                 }
             }
         }
     }
-    map
+    (map, pos)
 }
 
 fn move_hor(map: Map, pos: Pos, dx: i32) -> Map {
     let new_pos = (pos.0 + dx, pos.1);
-    let curr_obj = map[&pos];
+    let curr_obj = map[pos];
     let mut map = map;
-    match map[&new_pos] {
+    match map[new_pos] {
         Obj::Empty => {
-            map.insert(new_pos, curr_obj);
+            map[new_pos] = curr_obj;
         }
         Obj::RockLeft => {
             map = move_hor(map, new_pos, dx);
-            map.insert(new_pos, curr_obj);
-            map.insert(pos, Obj::Empty);
+            map[new_pos] = curr_obj;
+            map[pos] = Obj::Empty;
         }
         Obj::RockRight => {
             map = move_hor(map, new_pos, dx);
-            map.insert(new_pos, curr_obj);
-            map.insert(pos, Obj::Empty);
+            map[new_pos] = curr_obj;
+            map[pos] = Obj::Empty;
         }
         Obj::Wall => panic!("Invalid move"),
     }
@@ -102,7 +104,7 @@ fn move_hor(map: Map, pos: Pos, dx: i32) -> Map {
 
 fn can_move_hor(map: &Map, pos: Pos, dx: i32) -> bool {
     let new_pos = (pos.0 + dx, pos.1);
-    match map[&new_pos] {
+    match map[new_pos] {
         Obj::Empty => true,
         Obj::Wall => false,
         Obj::RockLeft => can_move_hor(map, new_pos, dx),
@@ -117,7 +119,7 @@ fn can_move_vert(map: &Map, positions: &HashSet<Pos>, dy: i32) -> bool {
     let mut colliding = HashSet::new();
     for (x, y) in positions {
         let new_pos = (*x, y + dy);
-        match map[&new_pos] {
+        match map[new_pos] {
             Obj::Empty => (),
             Obj::Wall => return false,
             Obj::RockLeft => {
@@ -143,7 +145,7 @@ fn move_vert(map: Map, positions: HashSet<Pos>, dy: i32) -> Map {
     let mut colliding: HashSet<Pos> = HashSet::new();
     for (x, y) in &positions {
         let new_pos = (*x, y + dy);
-        match map[&new_pos] {
+        match map[new_pos] {
             Obj::Empty => (),
             Obj::Wall => panic!("Invalid move"),
             Obj::RockLeft => {
@@ -161,9 +163,9 @@ fn move_vert(map: Map, positions: HashSet<Pos>, dy: i32) -> Map {
     map = move_vert(map, colliding, dy);
     for (x, y) in positions {
         let new_pos = (x, y + dy);
-        let curr_obj = map[&(x, y)];
-        map.insert(new_pos, curr_obj);
-        map.insert((x, y), Obj::Empty);
+        let curr_obj = map[(x, y)];
+        map[new_pos] = curr_obj;
+        map[(x, y)] = Obj::Empty;
     }
     map
 }
@@ -0,0 +1,72 @@
+use aoc_core::{AocError, Result};
+
+use super::*;
+
+pub fn parse(input: &str) -> Result<Warehouse> {
+    let (map, dirs) = input.split_once("\n\n").ok_or_else(|| {
+        AocError::at_end(input, "expected a blank line between the map and the moves")
+    })?;
+    let start = parse_start(input, map)?;
+    let map = parse_map(map)?;
+    let dirs = parse_dirs(input, dirs)?;
+    Ok((map, start, dirs))
+}
+
+fn parse_dirs(input: &str, dirs: &str) -> Result<Vec<Dir>> {
+    let mut parsed = Vec::new();
+    for line in dirs.lines() {
+        for (i, c) in line.char_indices() {
+            let dir = match c {
+                '^' => 0,
+                '>' => 1,
+                'v' => 2,
+                '<' => 3,
+                _ => {
+                    return Err(AocError::at(
+                        input,
+                        &line[i..],
+                        format!("unknown direction {:?}", c),
+                    ));
+                }
+            };
+            parsed.push(dir);
+        }
+    }
+    Ok(parsed)
+}
+
+/// Walking never checks bounds, so the map has to be walled in.
+fn parse_map(input: &str) -> Result<Map> {
+    use Obj::*;
+    let map = Grid::try_parse(input, |c| match c {
+        '.' => Some([Empty, Empty]),
+        '@' => Some([Empty, Empty]),
+        '#' => Some([Wall, Wall]),
+        'O' => Some([RockLeft, RockRight]),
+        _ => None,
+    })?;
+    let (width, height) = (map.width() as i32, map.height() as i32);
+    let on_edge = |(x, y): Pos| x == 0 || y == 0 || x == width - 1 || y == height - 1;
+    if let Some(((x, y), _)) = map
+        .iter()
+        .find(|&(pos, cell)| on_edge(pos) && cell[0] != Wall)
+    {
+        return Err(AocError::Parse {
+            line: y as usize + 1,
+            column: x as usize + 1,
+            message: "the warehouse is not walled in".to_string(),
+        });
+    }
+    Ok(Grid::from_rows(
+        map.rows()
+            .map(|row| row.iter().flatten().copied().collect())
+            .collect(),
+    ))
+}
+
+fn parse_start(input: &str, map: &str) -> Result<Pos> {
+    let (x, y) = Grid::try_parse(map, Some)?
+        .find(|&c| c == '@')
+        .ok_or_else(|| AocError::at(input, &map[map.len()..], "the map has no robot"))?;
+    Ok((x * 2, y))
+}
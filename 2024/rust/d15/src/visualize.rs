@@ -0,0 +1,87 @@
+use aoc_core::Result;
+use aoc_grid::{Grid, Pos};
+use aoc_vis::{Frame, Player, Render, Visualize};
+
+use super::{Day15, part1, part2};
+
+/// One of the parts' `step`s, making a single move.
+type Step<O> = fn(Grid<O>, Pos, usize) -> (Grid<O>, Pos);
+
+/// The robot partway through its moves around one of the warehouses.
+struct Robot<O> {
+    part: u8,
+    map: Grid<O>,
+    pos: Pos,
+    moves: usize,
+    total: usize,
+}
+
+impl<O> Robot<O> {
+    fn frame(&self, cell: impl FnMut(&O) -> (char, Option<usize>)) -> Frame {
+        let mut frame = Frame::from_grid(&self.map, cell).with_caption(format!(
+            "part {}, move {} of {}",
+            self.part, self.moves, self.total
+        ));
+        frame.set(self.pos, '@', Some(0));
+        frame
+    }
+}
+
+impl Render for Robot<part1::Obj> {
+    fn render(&self) -> Frame {
+        self.frame(|obj| match obj {
+            part1::Obj::Empty => ('.', None),
+            part1::Obj::Wall => ('#', None),
+            part1::Obj::Rock => ('O', Some(2)),
+        })
+    }
+}
+
+impl Render for Robot<part2::Obj> {
+    fn render(&self) -> Frame {
+        self.frame(|obj| match obj {
+            part2::Obj::Empty => ('.', None),
+            part2::Obj::Wall => ('#', None),
+            part2::Obj::RockLeft => ('[', Some(2)),
+            part2::Obj::RockRight => (']', Some(2)),
+        })
+    }
+}
+
+/// Plays the robot's moves around a warehouse, returning whether the viewer
+/// watched to the end.
+fn play<O: Clone>(
+    part: u8,
+    (map, start, dirs): &(Grid<O>, Pos, Vec<usize>),
+    step: Step<O>,
+    player: &mut Player,
+) -> bool
+where
+    Robot<O>: Render,
+{
+    let mut robot = Robot {
+        part,
+        map: map.clone(),
+        pos: *start,
+        moves: 0,
+        total: dirs.len(),
+    };
+    while player.show(&robot) {
+        let Some(&dir) = dirs.get(robot.moves) else {
+            return true;
+        };
+        (robot.map, robot.pos) = step(robot.map, robot.pos, dir);
+        robot.moves += 1;
+    }
+    false
+}
+
+impl Visualize for Day15 {
+    /// Plays the small warehouse and then the wide one.
+    fn visualize((small, wide): &Self::Input<'_>, player: &mut Player) -> Result<()> {
+        if play(1, small, part1::step, player) {
+            play(2, wide, part2::step, player);
+        }
+        Ok(())
+    }
+}
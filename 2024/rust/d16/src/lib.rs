@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use aoc_core::{Answer, AocError, Result, Solution};
+use aoc_grid::{step, Grid, Pos, DIRS};
+use aoc_image::{Export, Image, Picture};
+use aoc_path::{Paths, Preds, dijkstra};
+
+type Map = Grid<char>;
+const STEP_C: i32 = 1;
+const TURN_C: i32 = 1000;
+
+/// Where the reindeer is and which of `DIRS` it faces.
+type State = (Pos, usize);
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::try_parse(input, |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c))
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Answer> {
+        let paths = best_paths(map)?;
+        paths
+            .goal_cost()
+            .map(Answer::from)
+            .ok_or_else(unreachable_end)
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Answer> {
+        Ok(best_seats(map)?.len().into())
+    }
+}
+
+/// The pixels each tile is drawn as.
+const SCALE: usize = 4;
+
+impl Export for Day16 {
+    /// The maze with the best seats, the tiles on any best path, picked out.
+    fn export<'a>(map: &'a Self::Input<'_>) -> Result<Picture<'a>> {
+        let seats = best_seats(map)?;
+        let image = Image::from_cells(map.width(), map.height(), SCALE, |pos| match map[pos] {
+            'S' | 'E' => Some(0),
+            _ if seats.contains(&pos) => Some(1),
+            '#' => Some(6),
+            _ => None,
+        });
+        Ok(Picture::Still(image))
+    }
+}
+
+/// Every tile on at least one of the best paths.
+fn best_seats(map: &Map) -> Result<HashSet<Pos>> {
+    let paths = best_paths(map)?;
+    if paths.goals().is_empty() {
+        return Err(unreachable_end());
+    }
+    let seats = paths.on_shortest_paths(paths.goals());
+    Ok(seats.into_iter().map(|(pos, _)| pos).collect())
+}
+
+fn start(map: &Map) -> Result<Pos> {
+    map.find(|&cell| cell == 'S')
+        .ok_or_else(|| AocError::no_solution("the maze has no start"))
+}
+
+fn unreachable_end() -> AocError {
+    AocError::no_solution("the end cannot be reached from the start")
+}
+
+/// Every cheapest way from the start, facing east, to the end.
+fn best_paths(map: &Map) -> Result<Paths<State, i32>> {
+    let start = start(map)?;
+    Ok(dijkstra(
+        [(start, 1)],
+        |&(pos, dir)| {
+            let ahead = step(pos, DIRS[dir]);
+            let forward = map
+                .get(ahead)
+                .is_some_and(|&cell| cell != '#')
+                .then_some(((ahead, dir), STEP_C));
+            let turns = adj_dir(dir).map(|new_dir| ((pos, new_dir), TURN_C));
+            forward.into_iter().chain(turns)
+        },
+        |&(pos, _)| map[pos] == 'E',
+        Preds::All,
+    ))
+}
+
+fn adj_dir(dir: usize) -> [usize; 2] {
+    match dir {
+        0 => [1, 3],
+        1 => [0, 2],
+        2 => [1, 3],
+        3 => [0, 2],
+        _ => panic!("Invalid direction"),
+    }
+}
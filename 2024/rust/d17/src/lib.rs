@@ -0,0 +1,116 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{digit1, line_ending, u64};
+use nom::multi::separated_list1;
+use nom::{IResult, Parser};
+
+use aoc_core::error::finish;
+use aoc_core::{Answer, AocError, Result, Solution};
+
+pub mod quine;
+pub mod vm;
+use vm::{Instr, Regs, STEP_LIMIT, Vm};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = (Vec<Instr>, Vec<usize>, Regs);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((instr, _, regs): &Self::Input<'_>) -> Result<Answer> {
+        Ok(printout(Vm::new(instr, *regs).run(STEP_LIMIT)?).into())
+    }
+
+    fn part2((instr, goal, regs): &Self::Input<'_>) -> Result<Answer> {
+        quine::solve(instr, regs, goal)?
+            .map(Answer::from)
+            .ok_or_else(|| AocError::no_solution("no value of A makes the program print itself"))
+    }
+}
+
+fn printout(output: &[usize]) -> String {
+    output
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn parse(input: &str) -> Result<(Vec<Instr>, Vec<usize>, Regs)> {
+    let (regs, words) = finish(input, registers_and_program(input))?;
+    let mut program = Vec::with_capacity(words.len());
+    for word in &words {
+        match word.parse() {
+            Ok(value @ 0..8) => program.push(value),
+            _ => {
+                return Err(AocError::at(
+                    input,
+                    word,
+                    format!("expected a 3 bit number, found {}", word),
+                ));
+            }
+        }
+    }
+    // A last opcode without an operand is never run: reaching it ends the
+    // program like jumping past the end does. It is still part of the goal.
+    let instrs = program
+        .chunks_exact(2)
+        .zip(words.chunks_exact(2))
+        .map(|(pair, words)| {
+            Instr::decode(pair[0], pair[1])
+                .ok_or_else(|| AocError::at(input, words[1], "combo operand 7 is reserved"))
+        })
+        .collect::<Result<_>>()?;
+    Ok((instrs, program, regs))
+}
+
+/// The registers, then the program as its words, not yet checked.
+fn registers_and_program(input: &str) -> IResult<&str, (Regs, Vec<&str>)> {
+    let (input, _) = tag("Register A: ").parse(input)?;
+    let (input, a) = u64(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _) = tag("Register B: ").parse(input)?;
+    let (input, b) = u64(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _) = tag("Register C: ").parse(input)?;
+    let (input, c) = u64(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _) = tag("Program: ").parse(input)?;
+    let (input, words) = separated_list1(tag(","), digit1).parse(input)?;
+    Ok((input, ([a as usize, b as usize, c as usize], words)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_bad_programs() {
+        let registers = "Register A: 1\nRegister B: 0\nRegister C: 0\n\n";
+        let error = |program: &str| {
+            let input = format!("{}Program: {}\n", registers, program);
+            Day17::parse(&input).err().map(|err| err.to_string())
+        };
+        assert_eq!(error("0,3,5,4,3,0"), None);
+        assert_eq!(
+            error("0,3,8,4"),
+            Some("line 5, column 14: expected a 3 bit number, found 8".into())
+        );
+        assert_eq!(
+            error("5,7,3,0"),
+            Some("line 5, column 12: combo operand 7 is reserved".into())
+        );
+    }
+
+    #[test]
+    fn runs_odd_length_programs() {
+        let input = "Register A: 10\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,0\n";
+        let parsed = Day17::parse(input).unwrap();
+        assert_eq!(parsed.0, [Instr::Out(4)]);
+        assert_eq!(parsed.1, [5, 4, 0]);
+        assert_eq!(Day17::part1(&parsed).unwrap().to_string(), "2");
+    }
+}
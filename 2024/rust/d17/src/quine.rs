@@ -0,0 +1,422 @@
+//! Finding the lowest A that makes a program print itself.
+//!
+//! Inputs are all one loop that shifts a few bits off A, prints a digit
+//! worked out from what is left of A, and jumps back to the start until A is
+//! 0. For those the digits of A can be found one at a time, most significant
+//! first. Any other program is run symbolically instead, with every bit of
+//! every register kept as an xor of bits of the starting A.
+
+use aoc_core::{AocError, Result};
+
+use crate::vm::{Instr, Regs, STEP_LIMIT, Vm};
+
+/// How many instructions a symbolic run can take before the search gives up
+/// on the program.
+const SYMBOLIC_STEP_LIMIT: usize = 10_000;
+
+/// The lowest positive A that makes `program` print `goal`, or `None` if
+/// there is no such A.
+pub fn solve(program: &[Instr], regs: &Regs, goal: &[usize]) -> Result<Option<usize>> {
+    match Loop::of(program) {
+        Some(shape) => shape.solve(program, regs, goal),
+        None => symbolic(program, regs, goal),
+    }
+}
+
+/// A program that is a single loop over its whole body, which shifts `bits`
+/// bits off A once and prints once, and sets B and C from A before using
+/// them. Each pass then prints a digit that only depends on what is left of
+/// A, and the program prints one digit for every `bits` bits of A.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Loop {
+    pub bits: u32,
+}
+
+impl Loop {
+    /// The loop `program` is made of, if it has that shape.
+    pub fn of(program: &[Instr]) -> Option<Loop> {
+        let (Instr::Jnz(0), body) = program.split_last()? else {
+            return None;
+        };
+        let mut shifts = body.iter().filter_map(|instr| match instr {
+            Instr::Adv(op) => Some(*op),
+            _ => None,
+        });
+        let bits = match (shifts.next(), shifts.next()) {
+            (Some(bits @ 1..=3), None) => bits as u32,
+            _ => return None,
+        };
+        let outs = body.iter().filter(|instr| matches!(instr, Instr::Out(_)));
+        if outs.count() != 1 {
+            return None;
+        }
+        // Whether B and C have been set during this pass.
+        let mut set = [true, false, false];
+        for instr in body {
+            let (reads, writes) = match *instr {
+                Instr::Adv(op) | Instr::Out(op) => (combo_reg(op)?, None),
+                Instr::Bxl(_) => (Some(1), Some(1)),
+                Instr::Bst(op) | Instr::Bdv(op) => (combo_reg(op)?, Some(1)),
+                Instr::Cdv(op) => (combo_reg(op)?, Some(2)),
+                Instr::Bxc if !set[2] => return None,
+                Instr::Bxc => (Some(1), Some(1)),
+                Instr::Jnz(_) => return None,
+            };
+            if reads.is_some_and(|reg| !set[reg]) {
+                return None;
+            }
+            if let Some(reg) = writes {
+                set[reg] = true;
+            }
+        }
+        Some(Loop { bits })
+    }
+
+    /// Tries the digits of A from the most significant down, keeping those
+    /// that print the end of `goal` so far.
+    fn solve(&self, program: &[Instr], regs: &Regs, goal: &[usize]) -> Result<Option<usize>> {
+        self.search(program, regs, goal, 0, 0)
+    }
+
+    fn search(
+        &self,
+        program: &[Instr],
+        regs: &Regs,
+        goal: &[usize],
+        high: usize,
+        digits: usize,
+    ) -> Result<Option<usize>> {
+        if digits == goal.len() {
+            return Ok(Some(high));
+        }
+        let Some(shifted) = high.checked_mul(1 << self.bits) else {
+            return Ok(None);
+        };
+        for digit in 0..1 << self.bits {
+            let a = shifted + digit;
+            if a == 0 {
+                continue;
+            }
+            let mut regs = *regs;
+            regs[0] = a;
+            if Vm::new(program, regs).run(STEP_LIMIT)? != &goal[goal.len() - digits - 1..] {
+                continue;
+            }
+            if let Some(a) = self.search(program, &regs, goal, a, digits + 1)? {
+                return Ok(Some(a));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The register a combo operand reads, if any.
+fn combo_reg(op: usize) -> Option<Option<usize>> {
+    match op {
+        0..=3 => Some(None),
+        4..=6 => Some(Some(op - 4)),
+        _ => None,
+    }
+}
+
+/// An xor of some of the bits of the starting A, one bit each for bits 0 to
+/// 63, and of 1 if bit 64 is set.
+type Expr = u128;
+
+const ONE: Expr = 1 << 64;
+const VARS: Expr = ONE - 1;
+
+/// A register as an expression for each of its bits, lowest first.
+type Reg = [Expr; 64];
+
+fn constant(value: usize) -> Reg {
+    std::array::from_fn(|i| if value >> i & 1 == 1 { ONE } else { 0 })
+}
+
+fn shift(reg: &Reg, by: usize) -> Reg {
+    std::array::from_fn(|i| reg.get(i + by).copied().unwrap_or(0))
+}
+
+/// Linear equations over the bits of A, each an [`Expr`] that has to be 0,
+/// kept fully reduced. Each row is solved for its lowest bit, which no other
+/// row has.
+#[derive(Debug, Clone, Default)]
+struct System {
+    rows: Vec<Expr>,
+}
+
+impl System {
+    fn reduce(&self, mut expr: Expr) -> Expr {
+        for &row in &self.rows {
+            if expr & lowest(row) != 0 {
+                expr ^= row;
+            }
+        }
+        expr
+    }
+
+    /// The value of `expr` if the equations so far fix it.
+    fn value(&self, expr: Expr) -> Option<bool> {
+        let expr = self.reduce(expr);
+        (expr & VARS == 0).then_some(expr != 0)
+    }
+
+    /// Adds `expr == value`, or returns false if that contradicts the
+    /// equations so far.
+    fn require(&mut self, expr: Expr, value: bool) -> bool {
+        let expr = self.reduce(if value { expr ^ ONE } else { expr });
+        if expr & VARS == 0 {
+            return expr == 0;
+        }
+        let pivot = lowest(expr);
+        for row in &mut self.rows {
+            if *row & pivot != 0 {
+                *row ^= expr;
+            }
+        }
+        self.rows.push(expr);
+        true
+    }
+
+    /// The lowest positive A that solves the equations, if there is one.
+    ///
+    /// A bit no row is solved for is free, and each row's bit only depends
+    /// on free bits above it, so leaving every free bit 0 gives the lowest
+    /// solution. If that is 0, the lowest free bit alone gives the next.
+    fn lowest_positive(&self) -> Option<usize> {
+        let a = self.solve(0);
+        if a != 0 {
+            return Some(a);
+        }
+        let pivots = self
+            .rows
+            .iter()
+            .fold(0, |pivots, &row| pivots | lowest(row));
+        let free = !pivots & VARS;
+        (free != 0).then(|| self.solve(lowest(free)))
+    }
+
+    /// The solution with the free bits in `free` set and the rest 0.
+    fn solve(&self, free: Expr) -> usize {
+        let mut a = free as usize;
+        for &row in &self.rows {
+            let others = row & !lowest(row) & VARS;
+            let set = (row & ONE != 0) ^ ((others & free).count_ones() % 2 == 1);
+            if set {
+                a |= lowest(row) as usize;
+            }
+        }
+        a
+    }
+}
+
+fn lowest(expr: Expr) -> Expr {
+    expr & expr.wrapping_neg()
+}
+
+/// A symbolic run of the program partway through.
+#[derive(Debug, Clone)]
+struct State {
+    regs: [Reg; 3],
+    system: System,
+    next: usize,
+    printed: usize,
+    steps: usize,
+}
+
+impl State {
+    fn combo(&self, op: usize) -> Result<Reg> {
+        match op {
+            0..=3 => Ok(constant(op)),
+            4..=6 => Ok(self.regs[op - 4]),
+            _ => Err(AocError::Fault {
+                pc: self.next * 2,
+                message: format!("combo operand {}", op),
+            }),
+        }
+    }
+
+    /// Every value `reg` can have given the equations so far, each with the
+    /// equations that give it, with every value of at least `cap` as `cap`.
+    fn values(self, reg: &Reg, cap: usize) -> Vec<(State, usize)> {
+        let mut values = Vec::new();
+        self.split(reg, cap, 64, 0, &mut values);
+        values
+    }
+
+    fn split(self, reg: &Reg, cap: usize, bits: usize, high: usize, out: &mut Vec<(State, usize)>) {
+        if high >= cap {
+            out.push((self, cap));
+            return;
+        }
+        let Some(bit) = bits.checked_sub(1) else {
+            out.push((self, high));
+            return;
+        };
+        match self.system.value(reg[bit]) {
+            Some(set) => self.split(reg, cap, bit, high | usize::from(set) << bit, out),
+            None => {
+                for set in [false, true] {
+                    let mut state = self.clone();
+                    if state.system.require(reg[bit], set) {
+                        state.split(reg, cap, bit, high | usize::from(set) << bit, out);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Searches every way the program can run for the lowest A that prints
+/// `goal`, following both ways wherever the bits of A decide what happens.
+fn symbolic(program: &[Instr], regs: &Regs, goal: &[usize]) -> Result<Option<usize>> {
+    let a = std::array::from_fn(|i| 1 << i);
+    let mut stack = vec![State {
+        regs: [a, constant(regs[1]), constant(regs[2])],
+        system: System::default(),
+        next: 0,
+        printed: 0,
+        steps: 0,
+    }];
+    let mut best: Option<usize> = None;
+    while let Some(mut state) = stack.pop() {
+        let Some(&instr) = program.get(state.next) else {
+            if state.printed == goal.len()
+                && let Some(a) = state.system.lowest_positive()
+            {
+                best = Some(best.map_or(a, |best| best.min(a)));
+            }
+            continue;
+        };
+        if state.steps == SYMBOLIC_STEP_LIMIT {
+            return Err(AocError::no_solution(format!(
+                "gave up on a run of the program still going after {} steps",
+                SYMBOLIC_STEP_LIMIT
+            )));
+        }
+        state.steps += 1;
+        let [a, b, c] = state.regs;
+        let divide = |target: usize, op: usize, state: State| -> Result<Vec<State>> {
+            let amount = state.combo(op)?;
+            Ok(state
+                .values(&amount, 64)
+                .into_iter()
+                .map(|(mut state, by)| {
+                    state.regs[target] = shift(&a, by);
+                    state
+                })
+                .collect())
+        };
+        let mut next = match instr {
+            Instr::Adv(op) => divide(0, op, state)?,
+            Instr::Bdv(op) => divide(1, op, state)?,
+            Instr::Cdv(op) => divide(2, op, state)?,
+            Instr::Bxl(l) => {
+                state.regs[1] = std::array::from_fn(|i| b[i] ^ constant(l)[i]);
+                vec![state]
+            }
+            Instr::Bst(op) => {
+                let value = state.combo(op)?;
+                state.regs[1] = std::array::from_fn(|i| if i < 3 { value[i] } else { 0 });
+                vec![state]
+            }
+            Instr::Bxc => {
+                state.regs[1] = std::array::from_fn(|i| b[i] ^ c[i]);
+                vec![state]
+            }
+            Instr::Out(op) => {
+                let value = state.combo(op)?;
+                let Some(&digit) = goal.get(state.printed) else {
+                    continue;
+                };
+                if !(0..3).all(|i| state.system.require(value[i], digit >> i & 1 == 1)) {
+                    continue;
+                }
+                state.printed += 1;
+                vec![state]
+            }
+            Instr::Jnz(l) => {
+                let pc = state.next * 2;
+                let mut states = Vec::new();
+                for (mut state, nonzero) in state.values(&a, 1) {
+                    if nonzero == 0 {
+                        state.next += 1;
+                        states.push(state);
+                    } else if l % 2 == 1 {
+                        return Err(AocError::Fault {
+                            pc,
+                            message: format!("jumps inside the instruction at {}", l - 1),
+                        });
+                    } else {
+                        state.next = l / 2;
+                        states.push(state);
+                    }
+                }
+                // The jumps are taken and not taken in their own states.
+                stack.extend(states);
+                continue;
+            }
+        };
+        for state in &mut next {
+            state.next += 1;
+        }
+        stack.extend(next);
+    }
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognises_the_usual_loop() {
+        // The second example: A = A >> 3, print A, loop.
+        let program = [Instr::Adv(3), Instr::Out(4), Instr::Jnz(0)];
+        assert_eq!(Loop::of(&program), Some(Loop { bits: 3 }));
+        // Printing B before setting it depends on the pass before.
+        let program = [Instr::Adv(3), Instr::Out(5), Instr::Bst(4), Instr::Jnz(0)];
+        assert_eq!(Loop::of(&program), None);
+        // Two shifts a pass.
+        let program = [Instr::Adv(1), Instr::Adv(2), Instr::Out(4), Instr::Jnz(0)];
+        assert_eq!(Loop::of(&program), None);
+    }
+
+    #[test]
+    fn solves_symbolically() {
+        let program = [Instr::Adv(3), Instr::Out(4), Instr::Jnz(0)];
+        let goal = [0, 3, 5, 4, 3, 0];
+        assert_eq!(symbolic(&program, &[2024, 0, 0], &goal), Ok(Some(117440)));
+
+        // Shifting A by B, which is set from A itself.
+        let program = [
+            Instr::Bst(4),
+            Instr::Bxl(1),
+            Instr::Cdv(5),
+            Instr::Adv(3),
+            Instr::Out(6),
+            Instr::Jnz(0),
+        ];
+        let goal = Vm::new(&program, [0o5274, 0, 0])
+            .run(STEP_LIMIT)
+            .unwrap()
+            .to_vec();
+        // Four digits are printed for A up to 12 bits long.
+        let lowest =
+            (1..1 << 12).find(|&a| Vm::new(&program, [a, 0, 0]).run(STEP_LIMIT).unwrap() == goal);
+        assert_eq!(symbolic(&program, &[0, 0, 0], &goal), Ok(lowest));
+        assert_eq!(
+            Loop::of(&program)
+                .unwrap()
+                .solve(&program, &[0, 0, 0], &goal),
+            Ok(lowest)
+        );
+    }
+
+    #[test]
+    fn finds_no_a_when_there_is_none() {
+        // Prints A's lowest digit forever, or 0 once.
+        let program = [Instr::Out(4), Instr::Jnz(0)];
+        assert_eq!(solve(&program, &[0, 0, 0], &[5, 4, 3, 0]), Ok(None));
+    }
+}
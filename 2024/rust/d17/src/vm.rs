@@ -0,0 +1,314 @@
+use std::fmt::{self, Display};
+
+use aoc_core::{AocError, Result};
+
+pub type Regs = [usize; 3];
+
+const REG_NAMES: [char; 3] = ['A', 'B', 'C'];
+
+/// How many instructions [`Vm::run`] executes before giving up on a program
+/// that does not halt.
+pub const STEP_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instr {
+    Adv(usize),
+    Bxl(usize),
+    Bst(usize),
+    Jnz(usize),
+    Bxc,
+    Out(usize),
+    Bdv(usize),
+    Cdv(usize),
+}
+
+/// A combo operand, the literals 0 to 3 or one of the registers.
+struct Combo(usize);
+
+impl Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            0..=3 => write!(f, "{}", self.0),
+            4..=6 => write!(f, "{}", REG_NAMES[self.0 - 4]),
+            _ => write!(f, "?{}", self.0),
+        }
+    }
+}
+
+impl Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Instr::Adv(c) => write!(f, "adv A, {}", Combo(c)),
+            Instr::Bxl(l) => write!(f, "bxl B, {}", l),
+            Instr::Bst(c) => write!(f, "bst B, {} mod 8", Combo(c)),
+            Instr::Jnz(l) => write!(f, "jnz A, {}", l),
+            Instr::Bxc => write!(f, "bxc B, C"),
+            Instr::Out(c) => write!(f, "out {} mod 8", Combo(c)),
+            Instr::Bdv(c) => write!(f, "bdv B, A, {}", Combo(c)),
+            Instr::Cdv(c) => write!(f, "cdv C, A, {}", Combo(c)),
+        }
+    }
+}
+
+impl Instr {
+    /// The instruction for an opcode and operand, or `None` if the opcode is
+    /// not one or the instruction reads combo operand 7.
+    pub fn decode(opcode: usize, operand: usize) -> Option<Instr> {
+        let combo = (operand < 7).then_some(operand);
+        match opcode {
+            0 => combo.map(Instr::Adv),
+            1 => Some(Instr::Bxl(operand)),
+            2 => combo.map(Instr::Bst),
+            3 => Some(Instr::Jnz(operand)),
+            4 => Some(Instr::Bxc),
+            5 => combo.map(Instr::Out),
+            6 => combo.map(Instr::Bdv),
+            7 => combo.map(Instr::Cdv),
+            _ => None,
+        }
+    }
+
+    /// What the instruction does, written out.
+    fn effect(&self) -> String {
+        match *self {
+            Instr::Adv(c) => format!("A = A >> {}", Combo(c)),
+            Instr::Bxl(l) => format!("B = B ^ {}", l),
+            Instr::Bst(c) => format!("B = {} & 7", Combo(c)),
+            Instr::Jnz(l) => format!("if A != 0 goto {}", l),
+            Instr::Bxc => "B = B ^ C".to_string(),
+            Instr::Out(c) => format!("print {} & 7", Combo(c)),
+            Instr::Bdv(c) => format!("B = A >> {}", Combo(c)),
+            Instr::Cdv(c) => format!("C = A >> {}", Combo(c)),
+        }
+    }
+}
+
+/// The program as pseudo-assembly, one instruction a line with its address
+/// and what it does.
+pub fn disassemble(program: &[Instr]) -> String {
+    program
+        .iter()
+        .enumerate()
+        .map(|(i, instr)| {
+            format!(
+                "{:>3}: {:<16}; {}\n",
+                i * 2,
+                instr.to_string(),
+                instr.effect()
+            )
+        })
+        .collect()
+}
+
+/// What one instruction did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// The address of the instruction, counting in 3 bit words.
+    pub pc: usize,
+    pub instr: Instr,
+    /// The registers after the instruction.
+    pub regs: Regs,
+    pub out: Option<usize>,
+}
+
+/// The 3 bit computer running a program.
+pub struct Vm<'a> {
+    program: &'a [Instr],
+    regs: Regs,
+    /// The index of the next instruction, half its address.
+    next: usize,
+    steps: usize,
+    output: Vec<usize>,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a [Instr], regs: Regs) -> Self {
+        Vm {
+            program,
+            regs,
+            next: 0,
+            steps: 0,
+            output: Vec::new(),
+        }
+    }
+
+    pub fn regs(&self) -> Regs {
+        self.regs
+    }
+
+    /// How many instructions have run so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn output(&self) -> &[usize] {
+        &self.output
+    }
+
+    pub fn halted(&self) -> bool {
+        self.next >= self.program.len()
+    }
+
+    /// Runs the next instruction, or returns `None` once the program has
+    /// halted. Fails on instructions no program should contain: combo
+    /// operand 7, or a jump into the middle of an instruction.
+    pub fn step(&mut self) -> Result<Option<Step>> {
+        let Some(&instr) = self.program.get(self.next) else {
+            return Ok(None);
+        };
+        let pc = self.next * 2;
+        let [a, b, c] = self.regs;
+        let mut out = None;
+        let mut next = self.next + 1;
+        match instr {
+            Instr::Adv(op) => self.regs[0] = shift(a, self.combo(op, pc)?),
+            Instr::Bxl(l) => self.regs[1] = b ^ l,
+            Instr::Bst(op) => self.regs[1] = self.combo(op, pc)? % 8,
+            Instr::Jnz(l) if a != 0 => {
+                if l % 2 == 1 {
+                    return Err(AocError::Fault {
+                        pc,
+                        message: format!("jumps inside the instruction at {}", l - 1),
+                    });
+                }
+                next = l / 2;
+            }
+            Instr::Jnz(_) => {}
+            Instr::Bxc => self.regs[1] = b ^ c,
+            Instr::Out(op) => out = Some(self.combo(op, pc)? % 8),
+            Instr::Bdv(op) => self.regs[1] = shift(a, self.combo(op, pc)?),
+            Instr::Cdv(op) => self.regs[2] = shift(a, self.combo(op, pc)?),
+        }
+        self.next = next;
+        self.steps += 1;
+        self.output.extend(out);
+        Ok(Some(Step {
+            pc,
+            instr,
+            regs: self.regs,
+            out,
+        }))
+    }
+
+    /// Runs until the program halts, failing if it takes more than `limit`
+    /// steps.
+    pub fn run(&mut self, limit: usize) -> Result<&[usize]> {
+        while self.step_within(limit)?.is_some() {}
+        Ok(&self.output)
+    }
+
+    /// Every step until the program halts, failing if it takes more than
+    /// `limit` steps.
+    pub fn trace(&mut self, limit: usize) -> Result<Vec<Step>> {
+        let mut trace = Vec::new();
+        while let Some(step) = self.step_within(limit)? {
+            trace.push(step);
+        }
+        Ok(trace)
+    }
+
+    fn step_within(&mut self, limit: usize) -> Result<Option<Step>> {
+        if self.steps >= limit && !self.halted() {
+            return Err(AocError::no_solution(format!(
+                "the program is still running after {} steps",
+                limit
+            )));
+        }
+        self.step()
+    }
+
+    fn combo(&self, op: usize, pc: usize) -> Result<usize> {
+        match op {
+            0..=3 => Ok(op),
+            4..=6 => Ok(self.regs[op - 4]),
+            _ => Err(AocError::Fault {
+                pc,
+                message: format!("combo operand {}", op),
+            }),
+        }
+    }
+}
+
+/// Divides by `2^by`, which is 0 long before `by` gets too big to shift by.
+fn shift(value: usize, by: usize) -> usize {
+    u32::try_from(by)
+        .ok()
+        .and_then(|by| value.checked_shr(by))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The small examples from the puzzle text.
+    #[test]
+    fn runs_the_examples() {
+        let mut vm = Vm::new(&[Instr::Bst(6)], [0, 0, 9]);
+        vm.run(STEP_LIMIT).unwrap();
+        assert_eq!(vm.regs()[1], 1);
+
+        let program = [Instr::Adv(1), Instr::Out(4), Instr::Jnz(0)];
+        let mut vm = Vm::new(&program, [2024, 0, 0]);
+        assert_eq!(
+            vm.run(STEP_LIMIT).unwrap(),
+            [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]
+        );
+        assert_eq!(vm.regs()[0], 0);
+
+        let mut vm = Vm::new(&[Instr::Bxc], [0, 2024, 43690]);
+        vm.run(STEP_LIMIT).unwrap();
+        assert_eq!(vm.regs()[1], 44354);
+    }
+
+    #[test]
+    fn traces_steps() {
+        let program = [Instr::Adv(1), Instr::Out(4), Instr::Jnz(0)];
+        let trace = Vm::new(&program, [2, 0, 0]).trace(STEP_LIMIT).unwrap();
+        let pcs: Vec<_> = trace.iter().map(|step| step.pc).collect();
+        assert_eq!(pcs, [0, 2, 4, 0, 2, 4]);
+        assert_eq!(
+            trace[1],
+            Step {
+                pc: 2,
+                instr: Instr::Out(4),
+                regs: [1, 0, 0],
+                out: Some(1),
+            }
+        );
+    }
+
+    #[test]
+    fn stops_bad_programs() {
+        let forever = [Instr::Jnz(0)];
+        let mut vm = Vm::new(&forever, [1, 0, 0]);
+        assert!(matches!(vm.run(100), Err(AocError::NoSolution(_))));
+        assert_eq!(vm.steps(), 100);
+
+        let mut vm = Vm::new(&[Instr::Out(7)], [0, 0, 0]);
+        assert!(matches!(
+            vm.run(STEP_LIMIT),
+            Err(AocError::Fault { pc: 0, .. })
+        ));
+        let mut vm = Vm::new(&[Instr::Bxc, Instr::Jnz(1)], [1, 0, 0]);
+        assert_eq!(
+            vm.run(STEP_LIMIT).unwrap_err().to_string(),
+            "fault at 2: jumps inside the instruction at 0"
+        );
+
+        let mut vm = Vm::new(&[Instr::Adv(4)], [usize::MAX, 0, 0]);
+        vm.run(STEP_LIMIT).unwrap();
+        assert_eq!(vm.regs()[0], 0);
+    }
+
+    #[test]
+    fn disassembles() {
+        let program = [Instr::Adv(3), Instr::Out(5), Instr::Jnz(0)];
+        assert_eq!(
+            disassemble(&program),
+            "  0: adv A, 3        ; A = A >> 3\n  \
+             2: out B mod 8     ; print B & 7\n  \
+             4: jnz A, 0        ; if A != 0 goto 0\n"
+        );
+    }
+}
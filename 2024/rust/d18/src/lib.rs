@@ -0,0 +1,123 @@
+use nom::character::complete::i32;
+use nom::{
+    IResult, Parser,
+    bytes::complete::tag,
+    character::complete::line_ending,
+    combinator::{opt, verify},
+    multi::fold_many0,
+    sequence::separated_pair,
+};
+
+use aoc_core::error::finish;
+use aoc_core::{Answer, AocError, Params, Result, Solution};
+use aoc_grid::{Grid, Pos};
+use aoc_path::Preds;
+
+/// The step the first byte to land on each cell falls at, `usize::MAX`
+/// where none ever does.
+type Bytes = Grid<usize>;
+
+const TMAX: usize = 1024;
+const BOUNDS: i32 = 70;
+
+/// Steps from the top left to the bottom right corner once `tmax` bytes
+/// have fallen.
+fn bfs(bytes: &Bytes, tmax: usize) -> Option<usize> {
+    let goal = (bytes.width() as i32 - 1, bytes.height() as i32 - 1);
+    aoc_path::bfs(
+        [(0, 0)],
+        |&pos| bytes.neighbours(pos).filter(|&next| bytes[next] >= tmax),
+        |&pos| pos == goal,
+        Preds::One,
+    )
+    .goal_cost()
+}
+
+/// The memory space, how many bytes fall into it in all, and how many have
+/// fallen when part 1 starts.
+pub struct Memory {
+    bytes: Bytes,
+    count: usize,
+    fallen: usize,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Memory;
+
+    const PARAMS: &[&str] = &["bounds", "fallen"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        let bounds = params.get("bounds", BOUNDS)?;
+        if bounds < 0 {
+            return Err(AocError::Param(format!("bounds = {} is negative", bounds)));
+        }
+        let (bytes, count) = finish(input, parse(input, bounds))?;
+        Ok(Memory {
+            bytes,
+            count,
+            fallen: params.get("fallen", TMAX)?,
+        })
+    }
+
+    fn part1(memory: &Self::Input<'_>) -> Result<Answer> {
+        bfs(&memory.bytes, memory.fallen)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::no_solution("the exit is cut off"))
+    }
+
+    fn part2(memory: &Self::Input<'_>) -> Result<Answer> {
+        let (x, y) = first_blocking(&memory.bytes, memory.fallen, memory.count)
+            .ok_or_else(|| AocError::no_solution("the exit is never cut off"))?;
+        Ok(format!("{},{}", x, y).into())
+    }
+}
+
+fn first_blocking(bytes: &Bytes, tmin: usize, count: usize) -> Option<Pos> {
+    let blocked = (tmin..=count).find(|&n| bfs(bytes, n).is_none())?;
+    let last = blocked.checked_sub(1)?;
+    bytes.find(|&step| step == last)
+}
+
+/// The bytes, and how many of them there are.
+fn parse(input: &str, bounds: i32) -> IResult<&str, (Bytes, usize)> {
+    fold_many0(
+        |input| {
+            let in_bounds = |&(x, y): &Pos| (0..=bounds).contains(&x) && (0..=bounds).contains(&y);
+            let (input, (x, y)) =
+                verify(separated_pair(i32, tag(","), i32), in_bounds).parse(input)?;
+            let (input, _) = opt(line_ending).parse(input)?;
+            Ok((input, (x, y)))
+        },
+        || {
+            let size = bounds as usize + 1;
+            (Bytes::new(size, size, usize::MAX), 0)
+        },
+        |(mut bytes, acc), item| {
+            if bytes[item] == usize::MAX {
+                bytes[item] = acc;
+            }
+            (bytes, acc + 1)
+        },
+    ).parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_can_fall_on_the_same_cell() {
+        // The wall across the middle is finished by the fifth byte, and the
+        // repeated bytes change neither when nor where.
+        let params = Params::new().with("bounds", 2).with("fallen", 0);
+        let memory = Day18::parse_with("0,1\n0,1\n1,1\n0,1\n2,1\n1,1\n", &params).unwrap();
+        assert_eq!(memory.count, 6);
+        assert_eq!(Day18::part2(&memory).unwrap().to_string(), "2,1");
+    }
+}
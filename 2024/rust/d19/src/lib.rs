@@ -0,0 +1,55 @@
+mod parsing;
+
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
+use parsing::parse;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (Vec<Vec<char>>, Vec<Vec<char>>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1((towels, patterns): &Self::Input<'_>) -> Result<Answer> {
+        let mut memo = Memo::default();
+        Ok(patterns
+            .iter()
+            .map(|pat| num_matches(pat, towels, &mut memo))
+            .fold(0, |acc, num| acc + num.min(1))
+            .into())
+    }
+
+    fn part2((towels, patterns): &Self::Input<'_>) -> Result<Answer> {
+        let mut memo = Memo::default();
+        Ok(patterns
+            .iter()
+            .map(|pat| num_matches(pat, towels, &mut memo))
+            .sum::<u64>()
+            .into())
+    }
+}
+
+/// Ways to make the rest of a pattern, shared between patterns that end
+/// alike.
+type Memo<'a> = aoc_core::memo::Memo<&'a [char], u64>;
+
+fn num_matches<'a>(rem_pattern: &'a [char], towels: &[Vec<char>], memo: &mut Memo<'a>) -> u64 {
+    if rem_pattern.is_empty() {
+        return 1;
+    }
+    memo.get_or_compute(&rem_pattern, |memo| {
+        let mut num = 0;
+        for tow in towels {
+            if rem_pattern.len() < tow.len() {
+                continue;
+            }
+            if rem_pattern.iter().zip(tow.iter()).all(|(r, p)| r == p) {
+                num += num_matches(&rem_pattern[tow.len()..], towels, memo);
+            }
+        }
+        num
+    })
+}
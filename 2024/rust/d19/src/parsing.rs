@@ -8,16 +8,18 @@ use nom::{
     IResult, Parser,
 };
 
-pub fn parse(input: &str) -> IResult<&str, (Vec<Vec<char>>, Vec<Vec<char>>)> {
-    separated_pair(towels, line_ending, pats)(input)
+type Stripes = Vec<Vec<char>>;
+
+pub fn parse(input: &str) -> IResult<&str, (Stripes, Stripes)> {
+    separated_pair(towels, line_ending, pats).parse(input)
 }
 
 fn towels(input: &str) -> IResult<&str, Vec<Vec<char>>> {
-    many1(towel)(input)
+    many1(towel).parse(input)
 }
 
 fn towel(input: &str) -> IResult<&str, Vec<char>> {
-    if input.is_empty() || input.chars().next().unwrap() == '\n' {
+    if input.is_empty() || input.starts_with('\n') {
         return Err(nom::Err::Error(nom::error::Error::new(
             input,
             nom::error::ErrorKind::ManyTill,
@@ -29,7 +31,7 @@ fn towel(input: &str) -> IResult<&str, Vec<char>> {
 }
 
 fn pats(input: &str) -> IResult<&str, Vec<Vec<char>>> {
-    many1(pat)(input)
+    many1(pat).parse(input)
 }
 
 fn pat(input: &str) -> IResult<&str, Vec<char>> {
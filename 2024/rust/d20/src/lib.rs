@@ -0,0 +1,105 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{Answer, AocError, Params, Result, Solution};
+use aoc_grid::{Grid, Pos};
+
+type Map = Grid<char>;
+
+/// The least time a cheat has to save to be counted.
+const MIN_SAVED: i32 = 100;
+
+/// The racetrack, and the least time a cheat has to save.
+pub struct Race {
+    map: Map,
+    min_saved: i32,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Race;
+
+    const PARAMS: &[&str] = &["min_saved"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        Ok(Race {
+            map: Grid::try_parse(input, |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c))?,
+            min_saved: params.get("min_saved", MIN_SAVED)?,
+        })
+    }
+
+    fn part1(race: &Self::Input<'_>) -> Result<Answer> {
+        cheats(&race.map, 2, race.min_saved).map(Answer::from)
+    }
+
+    fn part2(race: &Self::Input<'_>) -> Result<Answer> {
+        cheats(&race.map, 20, race.min_saved).map(Answer::from)
+    }
+}
+
+fn cheats(map: &Map, ch_len: i32, min_saved: i32) -> Result<usize> {
+    let start = start(map)?;
+    let path = path(map, &start)?;
+    let s_cost = path.len() as i32 - 1;
+    Ok(num_cheats(s_cost, &path, ch_len, min_saved))
+}
+
+fn num_cheats(s_cost: i32, path: &[Pos], ch_len: i32, min_saved: i32) -> usize {
+    let mut acc = 0;
+    let p_len = path.len();
+    let cost_to_end: HashMap<Pos, i32> = path
+        .iter()
+        .enumerate()
+        .map(|(i, &pos)| (pos, (p_len - i - 1) as i32))
+        .collect();
+    for (cost_to_here, &pos) in path.iter().enumerate() {
+        for (opp, offset) in reachable_in_n_steps(&pos, ch_len) {
+            if let Some(cost_from_opp) = cost_to_end.get(&opp) {
+                let cost = cost_to_here as i32 + offset + cost_from_opp;
+                let saved = s_cost - cost;
+                if saved >= min_saved {
+                    acc += 1;
+                }
+            }
+        }
+    }
+    acc
+}
+
+fn reachable_in_n_steps(from: &Pos, n: i32) -> Vec<(Pos, i32)> {
+    let mut out = Vec::new();
+    for dy in -n..=n {
+        let left = n - dy.abs();
+        for dx in -left..=left {
+            let pos = (from.0 + dx, from.1 + dy);
+            let d = dx.abs() + dy.abs();
+            out.push((pos, d));
+        }
+    }
+    out
+}
+
+fn start(map: &Map) -> Result<Pos> {
+    map.find(|&cell| cell == 'S')
+        .ok_or_else(|| AocError::no_solution("the racetrack has no start"))
+}
+
+fn path(map: &Map, start: &Pos) -> Result<Vec<Pos>> {
+    let mut pos = *start;
+    let mut path = vec![pos];
+    let mut visited = HashSet::new();
+    while map[pos] != 'E' {
+        visited.insert(pos);
+        let next = map
+            .neighbours(pos)
+            .find(|next| !visited.contains(next) && map[*next] != '#')
+            .ok_or_else(|| AocError::no_solution("the track ends before the finish"))?;
+        path.push(next);
+        pos = next;
+    }
+    Ok(path)
+}
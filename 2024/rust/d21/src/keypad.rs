@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+type Pos = (i32, i32);
+
+/// The key every arm starts on, which presses the key the arm below points at.
+pub const ACTIVATE: char = 'A';
+
+/// Where the layouts have a gap.
+const GAP: char = ' ';
+
+/// A keypad as its rows of keys, with a space for each gap no arm may point
+/// at. Rows may be ragged, and anywhere past the end of one is a gap too.
+/// Directional keypads have `^`, `v`, `<` and `>` to move the arm below,
+/// and every keypad has [`ACTIVATE`].
+#[derive(Debug, Clone)]
+pub struct Keypad {
+    keys: Vec<(char, Pos)>,
+    positions: HashSet<Pos>,
+}
+
+impl Keypad {
+    pub fn new(rows: &[&str]) -> Self {
+        let mut keys = Vec::new();
+        for (y, row) in rows.iter().enumerate() {
+            for (x, key) in row.chars().enumerate() {
+                if key != GAP {
+                    keys.push((key, (x as i32, y as i32)));
+                }
+            }
+        }
+        let positions = keys.iter().map(|&(_, pos)| pos).collect();
+        Keypad { keys, positions }
+    }
+
+    /// The door's keypad.
+    pub fn numeric() -> Self {
+        Keypad::new(&["789", "456", "123", " 0A"])
+    }
+
+    /// The keypad each robot's arm is steered from.
+    pub fn directional() -> Self {
+        Keypad::new(&[" ^A", "<v>"])
+    }
+
+    pub fn contains(&self, key: char) -> bool {
+        self.position(key).is_some()
+    }
+
+    fn position(&self, key: char) -> Option<Pos> {
+        self.keys
+            .iter()
+            .find_map(|&(k, pos)| (k == key).then_some(pos))
+    }
+
+    /// The key one step from `key` in the direction of `arrow`, or `None`
+    /// if that is off the keypad or its gap.
+    pub fn step(&self, key: char, arrow: char) -> Option<char> {
+        let (x, y) = self.position(key)?;
+        let (dx, dy) = match arrow {
+            '<' => (-1, 0),
+            '>' => (1, 0),
+            '^' => (0, -1),
+            'v' => (0, 1),
+            _ => return None,
+        };
+        self.keys
+            .iter()
+            .find_map(|&(k, pos)| (pos == (x + dx, y + dy)).then_some(k))
+    }
+
+    /// Every shortest way of moving the arm from `from` to `to` and pressing
+    /// it without pointing at anything but keys, as the directional keys
+    /// that do it.
+    pub fn moves(&self, from: char, to: char) -> Vec<Vec<char>> {
+        let (Some(from), Some(to)) = (self.position(from), self.position(to)) else {
+            return Vec::new();
+        };
+        let mut moves = Vec::new();
+        self.extend(from, to, &mut Vec::new(), &mut moves);
+        moves
+    }
+
+    fn extend(&self, (x, y): Pos, to: Pos, path: &mut Vec<char>, moves: &mut Vec<Vec<char>>) {
+        if !self.positions.contains(&(x, y)) {
+            return;
+        }
+        if (x, y) == to {
+            let mut path = path.clone();
+            path.push(ACTIVATE);
+            moves.push(path);
+            return;
+        }
+        let (dx, dy) = ((to.0 - x).signum(), (to.1 - y).signum());
+        let steps = [
+            (dx, 0, if dx < 0 { '<' } else { '>' }),
+            (0, dy, if dy < 0 { '^' } else { 'v' }),
+        ];
+        for (dx, dy, key) in steps {
+            if (dx, dy) != (0, 0) {
+                path.push(key);
+                self.extend((x + dx, y + dy), to, path, moves);
+                path.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moves(pad: &Keypad, from: char, to: char) -> Vec<String> {
+        let mut moves: Vec<String> = pad
+            .moves(from, to)
+            .into_iter()
+            .map(|keys| keys.into_iter().collect())
+            .collect();
+        moves.sort();
+        moves
+    }
+
+    #[test]
+    fn moves_around_the_gap() {
+        let numeric = Keypad::numeric();
+        assert_eq!(moves(&numeric, 'A', '1'), ["<^<A", "^<<A"]);
+        assert_eq!(moves(&numeric, '7', '0'), [">vvvA", "v>vvA", "vv>vA"]);
+        assert_eq!(moves(&numeric, '5', '5'), ["A"]);
+
+        let directional = Keypad::directional();
+        assert_eq!(moves(&directional, '<', 'A'), [">>^A", ">^>A"]);
+        assert!(directional.moves('A', 'x').is_empty());
+        assert_eq!(numeric.step('0', '^'), Some('2'));
+        assert_eq!(numeric.step('0', '<'), None);
+    }
+
+    #[test]
+    fn keeps_off_every_gap() {
+        let pad = Keypad::new(&["1 2", "3", "45A"]);
+        assert_eq!(moves(&pad, '1', 'A'), ["vv>>A"]);
+        assert_eq!(moves(&pad, '3', '5'), ["v>A"]);
+        assert!(pad.moves('4', '2').is_empty());
+    }
+}
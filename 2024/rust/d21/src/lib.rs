@@ -0,0 +1,235 @@
+use aoc_core::{Answer, AocError, Params, Result, Solution};
+use keypad::{ACTIVATE, Keypad};
+
+/// Presses the human makes to move the arm over the keypad at some level
+/// from one key to another and press it.
+type Memo = aoc_core::memo::Memo<(char, char, usize), u64>;
+
+pub mod keypad;
+pub mod replay;
+mod visualize;
+
+/// How many robots stand between the human and the door in part 1.
+const ROBOTS: usize = 2;
+
+/// The codes to type, and how many robots part 1 and `run --visualize` type
+/// them through.
+pub struct Codes<'a> {
+    codes: Vec<&'a str>,
+    robots: usize,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Codes<'a>;
+
+    const PARAMS: &[&str] = &["robots"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        Ok(Codes {
+            codes: parse_codes(input)?,
+            robots: params.get("robots", ROBOTS)?,
+        })
+    }
+
+    fn part1(codes: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve(&codes.codes, codes.robots).into())
+    }
+
+    fn part2(codes: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve(&codes.codes, 25).into())
+    }
+}
+
+fn solve(codes: &[&str], robots: usize) -> u64 {
+    let mut chain = Chain::new(Keypad::numeric(), Keypad::directional(), robots);
+    codes
+        .iter()
+        .map(|code| chain.shortest(code) * numeric_part(code))
+        .sum()
+}
+
+/// The number the digits of `code` make, ignoring the rest.
+fn numeric_part(code: &str) -> u64 {
+    code.chars()
+        .filter_map(|c| c.to_digit(10))
+        .fold(0, |acc, digit| acc * 10 + u64::from(digit))
+}
+
+fn parse_codes(input: &str) -> Result<Vec<&str>> {
+    let numeric = Keypad::numeric();
+    input
+        .lines()
+        .map(|line| {
+            for (i, c) in line.char_indices() {
+                if !numeric.contains(c) {
+                    return Err(AocError::at(
+                        input,
+                        &line[i..],
+                        format!("{:?} is not on the numeric keypad", c),
+                    ));
+                }
+            }
+            Ok(line)
+        })
+        .collect()
+}
+
+/// The keypads from the human to the door. Level 0 is the directional
+/// keypad the human presses, each level up is the keypad the robot steered
+/// from the level below presses, and the top level is the door's.
+pub struct Chain {
+    door: Keypad,
+    directional: Keypad,
+    robots: usize,
+    memo: Memo,
+}
+
+impl Chain {
+    /// The keypad `door` typed on through `robots` robots, each steered from
+    /// a `directional` keypad.
+    pub fn new(door: Keypad, directional: Keypad, robots: usize) -> Self {
+        Chain {
+            door,
+            directional,
+            robots,
+            memo: Memo::default(),
+        }
+    }
+
+    /// The fewest presses the human can make to type `code` on the door.
+    /// What is worked out along the way is kept for later codes.
+    pub fn shortest(&mut self, code: &str) -> u64 {
+        let mut memo = std::mem::take(&mut self.memo);
+        let presses = self.presses(code.chars(), self.door(), &mut memo);
+        self.memo = memo;
+        presses
+    }
+
+    /// The presses the human makes to type `code` on the door in as few
+    /// presses as there can be. There are about two and a half times as many
+    /// for each robot added, so this is only for a few of them.
+    pub fn button_presses(&mut self, code: &str) -> String {
+        let mut memo = std::mem::take(&mut self.memo);
+        let mut presses = String::new();
+        self.sequence(code.chars(), self.door(), &mut memo, &mut presses);
+        self.memo = memo;
+        presses
+    }
+
+    /// What the human typing `presses` types on the door.
+    pub fn replay(&self, presses: &str) -> Result<String> {
+        replay::replay(&self.door, &self.directional, self.robots, presses)
+    }
+
+    fn door(&self) -> usize {
+        self.robots + 1
+    }
+
+    fn pad(&self, level: usize) -> &Keypad {
+        if level == self.door() {
+            &self.door
+        } else {
+            &self.directional
+        }
+    }
+
+    /// Presses the human makes to type `keys` on the keypad at `level`,
+    /// with its arm starting on [`ACTIVATE`].
+    fn presses(&self, keys: impl IntoIterator<Item = char>, level: usize, memo: &mut Memo) -> u64 {
+        let mut acc = 0;
+        let mut from = ACTIVATE;
+        for to in keys {
+            acc = self.press(from, to, level, memo).saturating_add(acc);
+            from = to;
+        }
+        acc
+    }
+
+    /// Pushes the presses the human makes to type `keys` on the keypad at
+    /// `level` onto `out`, taking the cheapest way of moving each arm.
+    fn sequence(
+        &self,
+        keys: impl IntoIterator<Item = char>,
+        level: usize,
+        memo: &mut Memo,
+        out: &mut String,
+    ) {
+        if level == 0 {
+            out.extend(keys);
+            return;
+        }
+
+        let mut from = ACTIVATE;
+        for to in keys {
+            let cheapest = self
+                .pad(level)
+                .moves(from, to)
+                .into_iter()
+                .min_by_key(|keys| self.presses(keys.iter().copied(), level - 1, memo));
+            if let Some(keys) = cheapest {
+                self.sequence(keys, level - 1, memo, out);
+            }
+            from = to;
+        }
+    }
+
+    /// The cheapest way to move the arm over the keypad at `level` from
+    /// `from` to `to` and press it, costing each way of moving it one level
+    /// down.
+    fn press(&self, from: char, to: char, level: usize, memo: &mut Memo) -> u64 {
+        if level == 0 {
+            return 1;
+        }
+
+        memo.get_or_compute(&(from, to, level), |memo| {
+            self.pad(level)
+                .moves(from, to)
+                .into_iter()
+                .map(|keys| self.presses(keys, level - 1, memo))
+                .min()
+                .unwrap_or(u64::MAX)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../fixtures/2024/21/example.txt");
+
+    #[test]
+    fn presses_type_the_code() {
+        for robots in 0..4 {
+            let mut chain = Chain::new(Keypad::numeric(), Keypad::directional(), robots);
+            for code in Day21::parse(EXAMPLE).unwrap().codes {
+                let presses = chain.button_presses(code);
+                assert_eq!(presses.len() as u64, chain.shortest(code));
+                assert_eq!(chain.replay(&presses), Ok(code.to_string()));
+            }
+        }
+    }
+
+    #[test]
+    fn types_on_other_layouts() {
+        let door = Keypad::new(&[" 12", "3A"]);
+        let mut chain = Chain::new(door, Keypad::directional(), 1);
+        let presses = chain.button_presses("312A");
+        assert_eq!(presses.len() as u64, chain.shortest("312A"));
+        assert_eq!(chain.replay(&presses), Ok("312A".to_string()));
+    }
+
+    #[test]
+    fn costs_each_pair_once() {
+        let mut chain = Chain::new(Keypad::numeric(), Keypad::directional(), 25);
+        let code = "0123456789A".repeat(20);
+        assert!(chain.shortest(&code) > 0);
+        assert!(chain.memo.len() <= 11 * 11 + 25 * 5 * 5);
+    }
+}
@@ -0,0 +1,76 @@
+use aoc_core::{AocError, Result};
+
+use crate::keypad::{ACTIVATE, Keypad};
+
+/// Presses `presses` on the human's `directional` keypad and follows them
+/// up through `robots` robots, giving what ends up typed on `door`. Fails on
+/// a press that is not a directional key, or one that moves an arm off its
+/// keypad or over a gap.
+pub fn replay(door: &Keypad, directional: &Keypad, robots: usize, presses: &str) -> Result<String> {
+    // The key each arm points at, from the one the human steers up to the
+    // one over the door.
+    let mut arms = vec![ACTIVATE; robots + 1];
+    let mut typed = String::new();
+    'presses: for (i, press) in presses.char_indices() {
+        if !directional.contains(press) {
+            return Err(AocError::at(
+                presses,
+                &presses[i..],
+                format!("{:?} is not on the directional keypad", press),
+            ));
+        }
+        let mut key = press;
+        for (level, arm) in arms.iter_mut().enumerate() {
+            if key == ACTIVATE {
+                key = *arm;
+                continue;
+            }
+            let pad = if level == robots { door } else { directional };
+            *arm = pad.step(*arm, key).ok_or_else(|| {
+                AocError::no_solution(format!(
+                    "press {} moves arm {} off its keypad from {:?}",
+                    i + 1,
+                    level + 1,
+                    arm
+                ))
+            })?;
+            continue 'presses;
+        }
+        typed.push(key);
+    }
+    Ok(typed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn types_the_code() {
+        let (door, directional) = (Keypad::numeric(), Keypad::directional());
+        let presses = "<vA<AA>>^AvAA<^A>A<v<A>>^AvA^A<vA>^A<v<A>^A>AAvA^A<v<A>A>^AAAvA<^A>A";
+        assert_eq!(
+            replay(&door, &directional, 2, presses),
+            Ok("029A".to_string())
+        );
+        assert_eq!(
+            replay(&door, &directional, 0, "<A^A>^^AvvvA"),
+            Ok("029A".to_string())
+        );
+    }
+
+    #[test]
+    fn stops_at_the_gap() {
+        let (door, directional) = (Keypad::numeric(), Keypad::directional());
+        assert_eq!(
+            replay(&door, &directional, 0, "<<A"),
+            Err(AocError::no_solution(
+                "press 2 moves arm 1 off its keypad from '0'"
+            ))
+        );
+        assert!(matches!(
+            replay(&door, &directional, 1, "<x"),
+            Err(AocError::Parse { column: 2, .. })
+        ));
+    }
+}
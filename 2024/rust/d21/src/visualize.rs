@@ -0,0 +1,80 @@
+use aoc_core::{AocError, Result};
+use aoc_vis::{Frame, Player, Render, Visualize};
+
+use super::keypad::{ACTIVATE, Keypad};
+use super::{Chain, Day21};
+
+/// How many presses are drawn on each line.
+const WIDTH: usize = 40;
+
+/// A code and the presses that type it through `robots` robots.
+struct Typed<'a> {
+    code: &'a str,
+    presses: String,
+    robots: usize,
+}
+
+impl Render for Typed<'_> {
+    fn render(&self) -> Frame {
+        let mut frame = Frame::new(WIDTH, self.presses.len().div_ceil(WIDTH));
+        for (i, key) in self.presses.chars().enumerate() {
+            let color = (key == ACTIVATE).then_some(0);
+            frame.set(((i % WIDTH) as i32, (i / WIDTH) as i32), key, color);
+        }
+        frame.with_caption(format!(
+            "{}: {} presses through {} robots",
+            self.code,
+            self.presses.len(),
+            self.robots
+        ))
+    }
+}
+
+/// `presses` for `code`, once replaying them through `chain` has shown they
+/// type it.
+fn checked<'a>(chain: &Chain, code: &'a str, presses: String) -> Result<Typed<'a>> {
+    let typed = chain.replay(&presses)?;
+    if typed != code {
+        return Err(AocError::no_solution(format!(
+            "the presses for {} type {}",
+            code, typed
+        )));
+    }
+    Ok(Typed {
+        code,
+        presses,
+        robots: chain.robots,
+    })
+}
+
+impl Visualize for Day21 {
+    /// Shows the presses for each code in turn, checking each by replaying
+    /// them through the robots. `--param robots=N` picks how many.
+    fn visualize(codes: &Self::Input<'_>, player: &mut Player) -> Result<()> {
+        let mut chain = Chain::new(Keypad::numeric(), Keypad::directional(), codes.robots);
+        for &code in &codes.codes {
+            let presses = chain.button_presses(code);
+            if !player.show(&checked(&chain, code, presses)?) {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_on_presses_for_another_code() {
+        let mut chain = Chain::new(Keypad::numeric(), Keypad::directional(), 2);
+        let presses = chain.button_presses("029A");
+        assert!(checked(&chain, "029A", presses.clone()).is_ok());
+        assert_eq!(
+            checked(&chain, "980A", presses).err(),
+            Some(AocError::no_solution("the presses for 980A type 029A"))
+        );
+        assert!(checked(&chain, "029A", "<<A".to_string()).is_err());
+    }
+}
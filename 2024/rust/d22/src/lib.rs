@@ -1,21 +1,36 @@
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
 use fxhash::FxHashMap;
 use nom::{
     character::complete::{i64, line_ending},
     multi::separated_list1,
-    IResult,
+    IResult, Parser,
 };
 
 const MODULUS: i64 = 16777216;
 
 type SeqLooup = FxHashMap<[i64; 4], i64>;
 
-fn main() {
-    let content = include_str!("input.txt");
-    let (_, numbers) = parse(content).unwrap();
-    let p1 = numbers.iter().map(|&n| nth_secret(n, 2000)).sum::<i64>();
-    let p2 = best_sequence2(&numbers);
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Answer> {
+        Ok(numbers
+            .iter()
+            .map(|&n| nth_secret(n, 2000))
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Result<Answer> {
+        Ok(best_sequence2(numbers).into())
+    }
 }
 
 fn best_sequence2(numbers: &[i64]) -> i64 {
@@ -55,7 +70,7 @@ fn build_lookup_table(init: i64) -> SeqLooup {
 }
 
 fn parse(input: &str) -> IResult<&str, Vec<i64>> {
-    separated_list1(line_ending, i64)(input)
+    separated_list1(line_ending, i64).parse(input)
 }
 
 fn nth_secret(number: i64, n: usize) -> i64 {
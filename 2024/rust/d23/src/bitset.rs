@@ -0,0 +1,105 @@
+/// A set of node indices below a fixed bound, one bit per index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// An empty set for indices below `len`.
+    pub fn new(len: usize) -> Self {
+        BitSet {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    pub fn insert(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    pub fn remove(&mut self, i: usize) {
+        self.words[i / 64] &= !(1 << (i % 64));
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        self.zip(other, |a, b| a & b)
+    }
+
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        self.zip(other, |a, b| a | b)
+    }
+
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        self.zip(other, |a, b| a & !b)
+    }
+
+    /// The size of the intersection, without building it.
+    pub fn intersection_len(&self, other: &BitSet) -> usize {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// The indices in the set, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+
+    fn zip(&self, other: &BitSet, f: impl Fn(u64, u64) -> u64) -> BitSet {
+        BitSet {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sets_bits_across_words() {
+        let mut a = BitSet::new(130);
+        let mut b = BitSet::new(130);
+        for i in [0, 63, 64, 129] {
+            a.insert(i);
+        }
+        for i in [63, 100, 129] {
+            b.insert(i);
+        }
+        assert_eq!(a.len(), 4);
+        assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [63, 129]);
+        assert_eq!(a.intersection_len(&b), 2);
+        assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), [0, 64]);
+        assert_eq!(a.union(&b).len(), 5);
+        a.remove(64);
+        assert_eq!(a.iter().collect::<Vec<_>>(), [0, 63, 129]);
+        assert!(BitSet::new(10).is_empty());
+    }
+}
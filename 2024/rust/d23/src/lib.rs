@@ -0,0 +1,223 @@
+use aoc_core::{Answer, AocError, Result, Solution};
+use bitset::BitSet;
+use fxhash::FxHashMap;
+
+mod bitset;
+
+/// The network, with the computers numbered in the order they first appear.
+pub struct Graph<'a> {
+    names: Vec<&'a str>,
+    neighbours: Vec<BitSet>,
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Graph<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(graph: &Self::Input<'_>) -> Result<Answer> {
+        Ok(triangles(graph).into())
+    }
+
+    fn part2(graph: &Self::Input<'_>) -> Result<Answer> {
+        let clique = largest_clique(graph);
+        if clique.is_empty() {
+            return Err(AocError::no_solution("there are no computers"));
+        }
+        let mut names: Vec<_> = clique.into_iter().map(|v| graph.names[v]).collect();
+        names.sort();
+        Ok(names.join(",").into())
+    }
+}
+
+/// Counts the sets of three computers all connected to each other where at
+/// least one name starts with 't', each set once by taking its computers in
+/// increasing order.
+fn triangles(graph: &Graph) -> usize {
+    let chief: Vec<bool> = graph
+        .names
+        .iter()
+        .map(|name| name.starts_with('t'))
+        .collect();
+    let mut count = 0;
+    for (u, neighbours) in graph.neighbours.iter().enumerate() {
+        for v in neighbours.iter().filter(|&v| v > u) {
+            count += neighbours
+                .intersection(&graph.neighbours[v])
+                .iter()
+                .filter(|&w| w > v && (chief[u] || chief[v] || chief[w]))
+                .count();
+        }
+    }
+    count
+}
+
+fn parse(input: &str) -> Result<Graph<'_>> {
+    let mut ids = FxHashMap::default();
+    let mut names = Vec::new();
+    let mut id = |name| {
+        *ids.entry(name).or_insert_with(|| {
+            names.push(name);
+            names.len() - 1
+        })
+    };
+    let mut edges = Vec::new();
+    for line in input.lines() {
+        let (node1, node2) = line
+            .split_once('-')
+            .filter(|(node1, node2)| !node1.is_empty() && !node2.is_empty() && !node2.contains('-'))
+            .ok_or_else(|| AocError::at(input, line, "expected two computers joined by '-'"))?;
+        edges.push((id(node1), id(node2)));
+    }
+
+    let mut neighbours = vec![BitSet::new(names.len()); names.len()];
+    for (u, v) in edges {
+        if u != v {
+            neighbours[u].insert(v);
+            neighbours[v].insert(u);
+        }
+    }
+    Ok(Graph { names, neighbours })
+}
+
+/// The computers in turn with the fewest connections to those not yet
+/// taken, so each has few connections to the ones after it.
+fn degeneracy_order(graph: &Graph) -> Vec<usize> {
+    let n = graph.names.len();
+    let mut degrees: Vec<_> = graph.neighbours.iter().map(BitSet::len).collect();
+    let mut taken = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    while let Some(v) = (0..n).filter(|&v| !taken[v]).min_by_key(|&v| degrees[v]) {
+        taken[v] = true;
+        order.push(v);
+        for u in graph.neighbours[v].iter() {
+            if !taken[u] {
+                degrees[u] -= 1;
+            }
+        }
+    }
+    order
+}
+
+/// The largest set of computers all connected to each other, found by
+/// Bron–Kerbosch starting from each computer in degeneracy order.
+fn largest_clique(graph: &Graph) -> Vec<usize> {
+    let n = graph.names.len();
+    let mut p = BitSet::new(n);
+    for v in 0..n {
+        p.insert(v);
+    }
+    let mut x = BitSet::new(n);
+    let mut best = Vec::new();
+    for v in degeneracy_order(graph) {
+        let neighbours = &graph.neighbours[v];
+        bron_kerbosch(
+            graph,
+            &mut vec![v],
+            p.intersection(neighbours),
+            x.intersection(neighbours),
+            &mut best,
+        );
+        p.remove(v);
+        x.insert(v);
+    }
+    best
+}
+
+/// Extends the clique `r` with computers from `p` into the cliques not
+/// already found through `x`, keeping the largest in `best`. Only the
+/// computers in `p` not connected to a pivot are tried, since any clique
+/// with none of them could add the pivot.
+fn bron_kerbosch(
+    graph: &Graph,
+    r: &mut Vec<usize>,
+    mut p: BitSet,
+    mut x: BitSet,
+    best: &mut Vec<usize>,
+) {
+    if p.is_empty() {
+        if x.is_empty() && r.len() > best.len() {
+            *best = r.clone();
+        }
+        return;
+    }
+    if r.len() + p.len() <= best.len() {
+        return;
+    }
+
+    let Some(pivot) = p
+        .union(&x)
+        .iter()
+        .max_by_key(|&u| p.intersection_len(&graph.neighbours[u]))
+    else {
+        return;
+    };
+    for v in p.difference(&graph.neighbours[pivot]).iter() {
+        let neighbours = &graph.neighbours[v];
+        r.push(v);
+        bron_kerbosch(
+            graph,
+            r,
+            p.intersection(neighbours),
+            x.intersection(neighbours),
+            best,
+        );
+        r.pop();
+        p.remove(v);
+        x.insert(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::rng::Rng;
+
+    use super::*;
+
+    #[test]
+    fn finds_a_clique_among_thousands() {
+        let name = |i: u64| format!("n{:04}", i);
+        let mut rng = Rng::new(0x2024_1223);
+        let mut input = String::new();
+        for i in 0..3000 {
+            for _ in 0..8 {
+                input += &format!("{}-{}\n", name(i), name(rng.below(3000)));
+            }
+        }
+        let clique: Vec<_> = (0..20).map(|i| name(i * 150)).collect();
+        for (i, a) in clique.iter().enumerate() {
+            for b in &clique[i + 1..] {
+                input += &format!("{}-{}\n", a, b);
+            }
+        }
+        let graph = Day23::parse(&input).unwrap();
+        assert_eq!(Day23::part2(&graph).unwrap().to_string(), clique.join(","));
+    }
+
+    #[test]
+    fn rejects_malformed_links() {
+        for input in [
+            "kh-tc\nqp-kh-ub\n",
+            "kh-tc\nqp-\n",
+            "kh-tc\n-ub\n",
+            "kh-tc\n\nqp-ub\n",
+        ] {
+            assert!(
+                matches!(
+                    Day23::parse(input),
+                    Err(AocError::Parse {
+                        line: 2,
+                        column: 1,
+                        ..
+                    })
+                ),
+                "{:?}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Repairing a ripple-carry adder with some gate outputs swapped.
+//!
+//! Each bit `i` of a working adder is a full adder:
+//!
+//! ```text
+//! x_i XOR y_i -> s_i        x_i AND y_i -> a_i
+//! s_i XOR c_i-1 -> z_i      s_i AND c_i-1 -> b_i
+//! a_i OR b_i -> c_i
+//! ```
+//!
+//! except for bit 0, which has no carry in, so `s_0` is `z_0` and `a_0` is
+//! `c_0`. The last carry out is the top bit of z.
+
+use aoc_core::rng::Rng;
+use aoc_core::{AocError, Result};
+use fxhash::FxHashMap;
+
+use crate::circuit::{Circuit, LANES};
+use crate::{Lookups, Op, Units};
+
+/// How many times a repaired adder has to get a lane's worth of random
+/// additions right.
+const CHECKS: usize = 2;
+
+/// The gates, found by what they compute from.
+struct Gates {
+    units: Units,
+    by_inputs: FxHashMap<(Op, String, String), String>,
+}
+
+impl Gates {
+    fn new(units: &Units) -> Self {
+        let by_inputs = units
+            .iter()
+            .map(|(out, (in1, op, in2))| (key(in1, *op, in2), out.clone()))
+            .collect();
+        Gates {
+            units: units.clone(),
+            by_inputs,
+        }
+    }
+
+    /// The wire the gate with `op` over `in1` and `in2` drives.
+    fn output(&self, in1: &str, op: Op, in2: &str) -> Option<&str> {
+        self.by_inputs.get(&key(in1, op, in2)).map(String::as_str)
+    }
+
+    /// The other input of an `op` gate with `input` as one of them.
+    fn partner(&self, input: &str, op: Op) -> Option<&str> {
+        self.units.values().find_map(|(in1, gate, in2)| match () {
+            _ if *gate != op => None,
+            _ if in1 == input => Some(in2.as_str()),
+            _ if in2 == input => Some(in1.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Swaps which gates drive wires `p` and `q`.
+    fn swap(&mut self, p: &str, q: &str) -> Result<()> {
+        let (Some(gate_p), Some(gate_q)) = (self.units.remove(p), self.units.remove(q)) else {
+            return Err(AocError::no_solution(format!(
+                "{} and {} are not both gate outputs",
+                p, q
+            )));
+        };
+        self.by_inputs
+            .insert(key(&gate_p.0, gate_p.1, &gate_p.2), q.to_string());
+        self.by_inputs
+            .insert(key(&gate_q.0, gate_q.1, &gate_q.2), p.to_string());
+        self.units.insert(q.to_string(), gate_p);
+        self.units.insert(p.to_string(), gate_q);
+        Ok(())
+    }
+
+    /// The first place going up from bit 0 where the gates stop making an
+    /// adder, as the two wires to swap to get past it, or `None` if it is
+    /// one all the way up.
+    fn first_fault(&self, bits: usize) -> Result<Option<(String, String)>> {
+        let swap = |p: &str, q: &str| Ok(Some((p.to_string(), q.to_string())));
+        let missing = |what: String| AocError::no_solution(format!("the adder has no {}", what));
+        let mut carry: Option<&str> = None;
+        for i in 0..bits {
+            let (x, y, z) = (wire('x', i), wire('y', i), wire('z', i));
+            let sum = self
+                .output(&x, Op::XOR, &y)
+                .ok_or_else(|| missing(format!("{} XOR {}", x, y)))?;
+            let and = self
+                .output(&x, Op::AND, &y)
+                .ok_or_else(|| missing(format!("{} AND {}", x, y)))?;
+            let Some(carry_in) = carry else {
+                if sum != z {
+                    return swap(sum, &z);
+                }
+                carry = Some(and);
+                continue;
+            };
+
+            match self.output(sum, Op::XOR, carry_in) {
+                Some(out) if out == z => {}
+                Some(out) => return swap(out, &z),
+                None => {
+                    // The gate for z has the right carry or the right sum,
+                    // and the other input is the one swapped.
+                    let Some((in1, Op::XOR, in2)) = self.units.get(&z) else {
+                        return Err(missing(format!("XOR gate for {}", z)));
+                    };
+                    let other = |wire: &str| {
+                        if in1 == wire {
+                            Some(in2)
+                        } else if in2 == wire {
+                            Some(in1)
+                        } else {
+                            None
+                        }
+                    };
+                    return match (other(carry_in), other(sum)) {
+                        (Some(should_be_sum), _) => swap(sum, should_be_sum),
+                        (_, Some(should_be_carry)) => swap(carry_in, should_be_carry),
+                        _ => Err(missing(format!("{} XOR {}", sum, carry_in))),
+                    };
+                }
+            }
+
+            let carried = self
+                .output(sum, Op::AND, carry_in)
+                .ok_or_else(|| missing(format!("{} AND {}", sum, carry_in)))?;
+            carry = match self.output(and, Op::OR, carried) {
+                Some(out) => Some(out),
+                None => {
+                    return match (self.partner(and, Op::OR), self.partner(carried, Op::OR)) {
+                        (Some(should_be_carried), _) => swap(carried, should_be_carried),
+                        (_, Some(should_be_and)) => swap(and, should_be_and),
+                        _ => Err(missing(format!("{} OR {}", and, carried))),
+                    };
+                }
+            };
+        }
+        let top = wire('z', bits);
+        match carry {
+            Some(carry) if carry != top => swap(carry, &top),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn key(in1: &str, op: Op, in2: &str) -> (Op, String, String) {
+    let (in1, in2) = if in1 <= in2 { (in1, in2) } else { (in2, in1) };
+    (op, in1.to_string(), in2.to_string())
+}
+
+fn wire(bus: char, bit: usize) -> String {
+    format!("{}{:02}", bus, bit)
+}
+
+/// The wires whose gates have to be swapped back for `units` to add the x
+/// and y inputs in `lookups`, sorted.
+pub fn repair(units: &Units, lookups: &Lookups) -> Result<Vec<String>> {
+    let bits = lookups.keys().filter(|wire| wire.starts_with('x')).count();
+    let mut gates = Gates::new(units);
+    let mut swapped = Vec::new();
+    while let Some((p, q)) = gates.first_fault(bits)? {
+        if swapped.len() >= units.len() {
+            return Err(AocError::no_solution(
+                "swapping gates does not fix the adder",
+            ));
+        }
+        gates.swap(&p, &q)?;
+        swapped.extend([p, q]);
+    }
+    check(&gates.units, lookups, bits)?;
+    swapped.sort();
+    Ok(swapped)
+}
+
+/// Makes sure `units` adds up some random numbers of `bits` bits right.
+fn check(units: &Units, lookups: &Lookups, bits: usize) -> Result<()> {
+    let circuit = Circuit::new(units, lookups)?;
+    let mask = if bits >= 64 {
+        u64::MAX
+    } else {
+        (1 << bits) - 1
+    };
+    let mut rng = Rng::new(0x2024_1224);
+    let mut random = || rng.next_u64() & mask;
+    for _ in 0..CHECKS {
+        let mut signals = circuit.signals(lookups);
+        let sums: Vec<_> = (0..LANES).map(|_| (random(), random())).collect();
+        for (lane, &(x, y)) in sums.iter().enumerate() {
+            circuit.set_bus(&mut signals, 'x', lane, x);
+            circuit.set_bus(&mut signals, 'y', lane, y);
+        }
+        circuit.eval(&mut signals);
+        for (lane, &(x, y)) in sums.iter().enumerate() {
+            let sum = circuit.read_bus(&signals, 'z', lane);
+            if u128::from(sum) != u128::from(x) + u128::from(y) {
+                return Err(AocError::no_solution(format!(
+                    "the repaired adder makes {} + {} = {}",
+                    x, y, sum
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day24;
+    use aoc_core::Solution;
+
+    /// An adder of `bits` bits in the puzzle's format, with the outputs of
+    /// each pair in `swaps` swapped.
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> String {
+        let mut text = String::new();
+        for bus in ['x', 'y'] {
+            for i in 0..bits {
+                text += &format!("{}: 0\n", wire(bus, i));
+            }
+        }
+        text += "\n";
+        let carry = |i: usize| {
+            if i + 1 == bits {
+                wire('z', bits)
+            } else {
+                wire('c', i)
+            }
+        };
+        let mut gate = |in1: String, op: &str, in2: String, out: String| {
+            let out = swaps
+                .iter()
+                .find_map(|&(p, q)| (out == p).then_some(q).or((out == q).then_some(p)))
+                .map_or(out, str::to_string);
+            text += &format!("{} {} {} -> {}\n", in1, op, in2, out);
+        };
+        for i in 0..bits {
+            let (x, y) = (wire('x', i), wire('y', i));
+            if i == 0 {
+                gate(x.clone(), "XOR", y.clone(), wire('z', 0));
+                gate(x, "AND", y, carry(0));
+                continue;
+            }
+            gate(x.clone(), "XOR", y.clone(), wire('s', i));
+            gate(x, "AND", y, wire('a', i));
+            gate(wire('s', i), "XOR", carry(i - 1), wire('z', i));
+            gate(wire('s', i), "AND", carry(i - 1), wire('b', i));
+            gate(wire('a', i), "OR", wire('b', i), carry(i));
+        }
+        text
+    }
+
+    #[test]
+    fn leaves_a_working_adder() {
+        let (units, lookups) = Day24::parse(&adder(8, &[])).unwrap();
+        assert_eq!(repair(&units, &lookups), Ok(vec![]));
+    }
+
+    #[test]
+    fn finds_swapped_wires() {
+        let swaps = [
+            ("s03", "a03"),
+            ("z05", "c05"),
+            ("z02", "b02"),
+            ("z07", "a07"),
+        ];
+        let input = Day24::parse(&adder(8, &swaps)).unwrap();
+        assert_eq!(
+            Day24::part2(&input).unwrap().to_string(),
+            "a03,a07,b02,c05,s03,z02,z05,z07"
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use aoc_core::{AocError, Result};
+use fxhash::FxHashMap;
+
+use crate::{Lookups, Op, Units};
+
+/// A wire, numbered in the order of the circuit's wire names.
+pub type WireId = usize;
+
+/// How many sets of inputs [`Circuit::eval`] runs through at once, one in
+/// each bit of a wire's signal.
+pub const LANES: usize = 64;
+
+/// The buses a circuit reads its inputs from and writes its sum to.
+const BUSES: [char; 3] = ['x', 'y', 'z'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gate {
+    pub in1: WireId,
+    pub op: Op,
+    pub in2: WireId,
+    pub out: WireId,
+}
+
+/// The gates of [`Units`] over numbered wires, ordered so every gate comes
+/// after the gates driving its inputs.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    names: Vec<String>,
+    ids: FxHashMap<String, WireId>,
+    gates: Vec<Gate>,
+    /// The wires of each of [`BUSES`].
+    buses: [Vec<WireId>; 3],
+}
+
+impl Circuit {
+    /// The circuit of `units` with the wires set in `lookups` as its inputs.
+    /// Fails if a wire is used but nothing sets it, or if gates feed into
+    /// each other in a loop.
+    pub fn new(units: &Units, lookups: &Lookups) -> Result<Self> {
+        let mut names: Vec<String> = units
+            .iter()
+            .flat_map(|(out, (in1, _, in2))| [out, in1, in2])
+            .chain(lookups.keys())
+            .cloned()
+            .collect();
+        names.sort();
+        names.dedup();
+        let ids: FxHashMap<_, _> = names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| (name.clone(), id))
+            .collect();
+
+        let mut drivers: Vec<Option<Gate>> = vec![None; names.len()];
+        for (out, (in1, op, in2)) in units {
+            let out = ids[out];
+            drivers[out] = Some(Gate {
+                in1: ids[in1],
+                op: *op,
+                in2: ids[in2],
+                out,
+            });
+        }
+        for (id, name) in names.iter().enumerate() {
+            if drivers[id].is_none() && !lookups.contains_key(name) {
+                return Err(AocError::no_solution(format!(
+                    "wire {} is used but nothing sets it",
+                    name
+                )));
+            }
+        }
+
+        let buses = BUSES.map(|bus| bus_wires(&names, bus));
+        let mut circuit = Circuit {
+            names,
+            ids,
+            gates: Vec::new(),
+            buses,
+        };
+        circuit.gates = circuit.order(&drivers)?;
+        Ok(circuit)
+    }
+
+    /// The gates in `drivers` with each after those its inputs come from,
+    /// found depth first.
+    fn order(&self, drivers: &[Option<Gate>]) -> Result<Vec<Gate>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            New,
+            Open,
+            Done,
+        }
+        let mut marks = vec![Mark::New; drivers.len()];
+        let mut gates = Vec::with_capacity(drivers.len());
+        for start in 0..drivers.len() {
+            // The wires being worked out, each with whether its inputs have
+            // been pushed yet.
+            let mut stack = vec![(start, false)];
+            while let Some((wire, expanded)) = stack.pop() {
+                let Some(gate) = drivers[wire] else {
+                    marks[wire] = Mark::Done;
+                    continue;
+                };
+                if expanded {
+                    marks[wire] = Mark::Done;
+                    gates.push(gate);
+                    continue;
+                }
+                match marks[wire] {
+                    Mark::Done => continue,
+                    Mark::Open => unreachable!("open wires are only on the stack once"),
+                    Mark::New => {}
+                }
+                marks[wire] = Mark::Open;
+                stack.push((wire, true));
+                for input in [gate.in1, gate.in2] {
+                    match marks[input] {
+                        Mark::New => stack.push((input, false)),
+                        Mark::Open => return Err(self.cycle(&stack, input)),
+                        Mark::Done => {}
+                    }
+                }
+            }
+        }
+        Ok(gates)
+    }
+
+    /// The loop through `wire` in the wires still open on `stack`.
+    fn cycle(&self, stack: &[(WireId, bool)], wire: WireId) -> AocError {
+        let open: Vec<_> = stack
+            .iter()
+            .filter(|(_, expanded)| *expanded)
+            .map(|&(wire, _)| wire)
+            .collect();
+        let start = open.iter().position(|&open| open == wire).unwrap_or(0);
+        let names: Vec<_> = open[start..]
+            .iter()
+            .chain([&wire])
+            .map(|&wire| self.name(wire))
+            .collect();
+        AocError::no_solution(format!("the gates loop round {}", names.join(" <- ")))
+    }
+
+    pub fn id(&self, name: &str) -> Option<WireId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, wire: WireId) -> &str {
+        &self.names[wire]
+    }
+
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// The wires of the x, y or z bus, lowest bit first. Other letters have
+    /// no wires.
+    pub fn bus(&self, bus: char) -> &[WireId] {
+        match BUSES.iter().position(|&b| b == bus) {
+            Some(i) => &self.buses[i],
+            None => &[],
+        }
+    }
+
+    /// Signals for every wire with the inputs in `lookups` in every lane.
+    pub fn signals(&self, lookups: &Lookups) -> Vec<u64> {
+        let mut signals = vec![0; self.names.len()];
+        for (name, &value) in lookups {
+            signals[self.ids[name]] = if value { u64::MAX } else { 0 };
+        }
+        signals
+    }
+
+    /// Works out the signal on every gate's output from its inputs, for all
+    /// [`LANES`] at once.
+    pub fn eval(&self, signals: &mut [u64]) {
+        for gate in &self.gates {
+            let (in1, in2) = (signals[gate.in1], signals[gate.in2]);
+            signals[gate.out] = match gate.op {
+                Op::AND => in1 & in2,
+                Op::OR => in1 | in2,
+                Op::XOR => in1 ^ in2,
+            };
+        }
+    }
+
+    /// Sets the wires of `bus` in `lane` to the bits of `value`.
+    pub fn set_bus(&self, signals: &mut [u64], bus: char, lane: usize, value: u64) {
+        for (bit, &wire) in self.bus(bus).iter().enumerate() {
+            let set = bit < 64 && value >> bit & 1 == 1;
+            signals[wire] = signals[wire] & !(1 << lane) | u64::from(set) << lane;
+        }
+    }
+
+    /// The number on the wires of `bus` in `lane`.
+    pub fn read_bus(&self, signals: &[u64], bus: char, lane: usize) -> u64 {
+        self.bus(bus)
+            .iter()
+            .enumerate()
+            .filter(|&(bit, &wire)| bit < 64 && signals[wire] >> lane & 1 == 1)
+            .fold(0, |value, (bit, _)| value | 1 << bit)
+    }
+}
+
+/// The wires among `names` named by `bus` followed by their bit, lowest bit
+/// first.
+fn bus_wires(names: &[String], bus: char) -> Vec<WireId> {
+    let mut wires: Vec<(usize, WireId)> = names
+        .iter()
+        .enumerate()
+        .filter_map(|(id, name)| {
+            let bit = name.strip_prefix(bus)?.parse().ok()?;
+            Some((bit, id))
+        })
+        .collect();
+    wires.sort();
+    wires.into_iter().map(|(_, id)| id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day24;
+    use aoc_core::Solution;
+
+    const EXAMPLE: &str = include_str!("../../../../fixtures/2024/24/example.txt");
+
+    fn circuit(input: &str) -> Result<Circuit> {
+        let (units, lookups) = Day24::parse(input)?;
+        Circuit::new(&units, &lookups)
+    }
+
+    #[test]
+    fn orders_gates_after_their_inputs() {
+        let circuit = circuit(EXAMPLE).unwrap();
+        let mut ready: Vec<_> = ['x', 'y']
+            .into_iter()
+            .flat_map(|bus| circuit.bus(bus).iter().copied())
+            .collect();
+        for gate in circuit.gates() {
+            assert!(ready.contains(&gate.in1) && ready.contains(&gate.in2));
+            ready.push(gate.out);
+        }
+        assert_eq!(circuit.bus('z').len(), 13);
+    }
+
+    #[test]
+    fn runs_lanes_side_by_side() {
+        let (units, lookups) = Day24::parse(EXAMPLE).unwrap();
+        let circuit = Circuit::new(&units, &lookups).unwrap();
+        let mut signals = circuit.signals(&lookups);
+        circuit.set_bus(&mut signals, 'x', 1, 0);
+        circuit.set_bus(&mut signals, 'y', 1, 0);
+        circuit.eval(&mut signals);
+        assert_eq!(circuit.read_bus(&signals, 'z', 0), 2024);
+        assert_eq!(circuit.read_bus(&signals, 'z', 63), 2024);
+        assert_ne!(circuit.read_bus(&signals, 'z', 1), 2024);
+    }
+
+    #[test]
+    fn reports_broken_circuits() {
+        let err = circuit("x00: 1\n\nx00 AND q -> z00\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no solution: wire q is used but nothing sets it"
+        );
+
+        let err = circuit("x00: 1\n\nx00 AND b -> a\nx00 OR a -> b\na XOR b -> z00\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no solution: the gates loop round a <- b <- a"
+        );
+    }
+}
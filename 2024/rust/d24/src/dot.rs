@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::{Lookups, Op, Units};
+
+const HIGHLIGHT: &str = "red";
+
+fn color(op: Op) -> &'static str {
+    match op {
+        Op::AND => "lightblue",
+        Op::OR => "palegreen",
+        Op::XOR => "gold",
+    }
+}
+
+/// The circuit as a Graphviz graph, with a node for each input wire and each
+/// gate, named after the wire it drives. Inputs are lined up along the top
+/// and the z outputs along the bottom, both in bit order, and the gates
+/// driving the wires in `highlight` are outlined.
+pub fn circuit(units: &Units, lookups: &Lookups, highlight: &[String]) -> String {
+    // Sorted so the same circuit always gives the same graph.
+    let gates: BTreeMap<_, _> = units.iter().collect();
+    let mut inputs: Vec<_> = lookups.keys().collect();
+    inputs.sort_by_key(|wire| {
+        let bus = wire.char_indices().nth(1).map_or(wire.len(), |(i, _)| i);
+        (&wire[bus..], &wire[..bus])
+    });
+    let outputs: Vec<_> = gates
+        .keys()
+        .copied()
+        .filter(|wire| wire.starts_with('z'))
+        .collect();
+
+    let mut dot = String::from("digraph circuit {\n");
+    dot += "  node [shape=box, style=filled, fillcolor=white];\n";
+    for wire in &inputs {
+        writeln!(dot, "  \"{}\" [shape=circle];", wire).unwrap();
+    }
+    for (out, (in1, op, in2)) in &gates {
+        let outline = if highlight.contains(out) {
+            format!(", color={}, penwidth=3", HIGHLIGHT)
+        } else {
+            String::new()
+        };
+        writeln!(
+            dot,
+            "  \"{}\" [label=\"{:?}\\n{}\", fillcolor={}{}];",
+            out,
+            op,
+            out,
+            color(*op),
+            outline
+        )
+        .unwrap();
+        writeln!(dot, "  \"{}\" -> \"{}\";", in1, out).unwrap();
+        writeln!(dot, "  \"{}\" -> \"{}\";", in2, out).unwrap();
+    }
+    rank(&mut dot, "source", &inputs);
+    rank(&mut dot, "sink", &outputs);
+    dot += "}\n";
+    dot
+}
+
+/// Puts `wires` on one rank, kept in order by invisible edges between them.
+fn rank(dot: &mut String, rank: &str, wires: &[&String]) {
+    if wires.is_empty() {
+        return;
+    }
+    let names: Vec<_> = wires.iter().map(|wire| format!("\"{}\"", wire)).collect();
+    writeln!(dot, "  {{ rank={}; {}; }}", rank, names.join("; ")).unwrap();
+    if wires.len() > 1 {
+        writeln!(dot, "  {} [style=invis];", names.join(" -> ")).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day24;
+    use aoc_core::Solution;
+
+    const SMALL: &str = include_str!("../../../../fixtures/2024/24/small.txt");
+
+    #[test]
+    fn draws_the_circuit() {
+        let (units, lookups) = Day24::parse(SMALL).unwrap();
+        assert_eq!(
+            circuit(&units, &lookups, &["z01".to_string()]),
+            r#"digraph circuit {
+  node [shape=box, style=filled, fillcolor=white];
+  "x00" [shape=circle];
+  "y00" [shape=circle];
+  "x01" [shape=circle];
+  "y01" [shape=circle];
+  "x02" [shape=circle];
+  "y02" [shape=circle];
+  "z00" [label="AND\nz00", fillcolor=lightblue];
+  "x00" -> "z00";
+  "y00" -> "z00";
+  "z01" [label="XOR\nz01", fillcolor=gold, color=red, penwidth=3];
+  "x01" -> "z01";
+  "y01" -> "z01";
+  "z02" [label="OR\nz02", fillcolor=palegreen];
+  "x02" -> "z02";
+  "y02" -> "z02";
+  { rank=source; "x00"; "y00"; "x01"; "y01"; "x02"; "y02"; }
+  "x00" -> "y00" -> "x01" -> "y01" -> "x02" -> "y02" [style=invis];
+  { rank=sink; "z00"; "z01"; "z02"; }
+  "z00" -> "z01" -> "z02" [style=invis];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn sorts_any_wire_names() {
+        let lookups: Lookups = [("ä1".to_string(), true), ("x".to_string(), false)]
+            .into_iter()
+            .collect();
+        let units: Units = [(
+            "z00".to_string(),
+            ("ä1".to_string(), Op::AND, "x".to_string()),
+        )]
+        .into_iter()
+        .collect();
+        let dot = circuit(&units, &lookups, &[]);
+        assert!(dot.contains("{ rank=source; \"x\"; \"ä1\"; }"));
+    }
+}
@@ -0,0 +1,50 @@
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
+use aoc_image::{Export, Picture};
+use fxhash::FxHashMap;
+type Units = FxHashMap<String, (String, Op, String)>;
+type Lookups = FxHashMap<String, bool>;
+
+mod adder;
+pub mod circuit;
+pub mod dot;
+mod parsing;
+use crate::parsing::parse;
+use circuit::Circuit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    OR,
+    AND,
+    XOR,
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = (Units, Lookups);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1((units, lookups): &Self::Input<'_>) -> Result<Answer> {
+        let circuit = Circuit::new(units, lookups)?;
+        let mut signals = circuit.signals(lookups);
+        circuit.eval(&mut signals);
+        Ok(circuit.read_bus(&signals, 'z', 0).into())
+    }
+
+    fn part2((units, lookups): &Self::Input<'_>) -> Result<Answer> {
+        Ok(adder::repair(units, lookups)?.join(",").into())
+    }
+}
+
+impl Export for Day24 {
+    /// The circuit as a graph, with the gates the adder repair would swap
+    /// outlined if it can fix the circuit.
+    fn export<'a>((units, lookups): &'a Self::Input<'_>) -> Result<Picture<'a>> {
+        let swapped = adder::repair(units, lookups).unwrap_or_default();
+        Ok(Picture::Graph(dot::circuit(units, lookups, &swapped)))
+    }
+}
@@ -9,7 +9,7 @@ use fxhash::FxHashMap;
 use nom::{
     bytes::complete::tag,
     character::complete::{alphanumeric1, space1},
-    IResult,
+    IResult, Parser,
 };
 
 use super::*;
@@ -17,20 +17,20 @@ pub fn parse(input: &str) -> IResult<&str, (Units, Lookups)> {
     let (input, lookups) = fold_many1(lookup, FxHashMap::default, |mut acc, (id, value)| {
         acc.insert(id, value);
         acc
-    })(input)?;
+    }).parse(input)?;
     let (input, _) = line_ending(input)?;
     let (input, units) = fold_many1(gate, FxHashMap::default, |mut acc, (out, in1, op, in2)| {
         acc.insert(out, (in1, op, in2));
         acc
-    })(input)?;
+    }).parse(input)?;
     Ok((input, (units, lookups)))
 }
 
 fn lookup(input: &str) -> IResult<&str, (String, bool)> {
     let (input, id) = alphanumeric1(input)?;
-    let (input, _) = tag(":")(input)?;
+    let (input, _) = tag(":").parse(input)?;
     let (input, _) = space1(input)?;
-    let (input, value) = alt((value(true, tag("1")), value(false, tag("0"))))(input)?;
+    let (input, value) = alt((value(true, tag("1")), value(false, tag("0")))).parse(input)?;
     let (input, _) = line_ending(input)?;
     Ok((input, (id.into(), value)))
 }
@@ -42,13 +42,13 @@ fn gate(input: &str) -> IResult<&str, (String, String, Op, String)> {
         value(Op::OR, tag("OR")),
         value(Op::AND, tag("AND")),
         value(Op::XOR, tag("XOR")),
-    ))(input)?;
+    )).parse(input)?;
     let (input, _) = space1(input)?;
     let (input, in2) = alphanumeric1(input)?;
     let (input, _) = space1(input)?;
-    let (input, _) = tag("->")(input)?;
+    let (input, _) = tag("->").parse(input)?;
     let (input, _) = space1(input)?;
     let (input, out) = alphanumeric1(input)?;
-    let (input, _) = opt(line_ending)(input)?;
+    let (input, _) = opt(line_ending).parse(input)?;
     Ok((input, (out.into(), in1.into(), op, in2.into())))
 }
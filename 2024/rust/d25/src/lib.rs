@@ -0,0 +1,66 @@
+use aoc_core::{Answer, AocError, Result, Solution};
+use fxhash::FxHashSet;
+
+type Pins = FxHashSet<[usize; 5]>;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = (Pins, Pins);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((keys, locks): &Self::Input<'_>) -> Result<Answer> {
+        let mut acc = 0;
+        for key in keys {
+            let fs = locks.iter().filter(|lock| fits(key, lock)).count();
+            acc += fs;
+        }
+        Ok(acc.into())
+    }
+}
+
+fn fits(key: &[usize; 5], lock: &[usize; 5]) -> bool {
+    for i in 0..5 {
+        if key[i] + lock[i] > 5 {
+            return false;
+        }
+    }
+    true
+}
+
+fn parse(input: &str) -> Result<(Pins, Pins)> {
+    let mut keys = Pins::default();
+    let mut locks = Pins::default();
+    for pins in input.split("\n\n") {
+        let charmat: Vec<Vec<_>> = pins.lines().map(|line| line.chars().collect()).collect();
+        if charmat.len() < 2 || charmat.iter().any(|row| row.len() != 5) {
+            return Err(AocError::at(
+                input,
+                pins,
+                "expected a key or lock 5 pins wide",
+            ));
+        }
+        let pins = pin_numbers(&charmat[1..charmat.len() - 1]);
+        if charmat[0][0] == '#' {
+            locks.insert(pins);
+        } else {
+            keys.insert(pins);
+        }
+    }
+    Ok((keys, locks))
+}
+
+fn pin_numbers(input: &[Vec<char>]) -> [usize; 5] {
+    let mut pins = [0; 5];
+    for row in input {
+        for (x, &c) in row.iter().enumerate() {
+            if c == '#' {
+                pins[x] += 1;
+            }
+        }
+    }
+    pins
+}
@@ -1,5 +1,5 @@
-use std::cmp::{max, min};
-
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
 use nom::{
     IResult, Parser,
     branch::alt,
@@ -13,20 +13,33 @@ use nom::{
 const GRID_SIZE: i32 = 100;
 
 #[derive(Debug)]
-enum Command {
+pub enum Command {
     Left(i32),
     Right(i32),
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_, commands) = parse_commands(input).expect("Failed to parse commands");
-    let (states, xx) = all_states(&commands);
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse_commands(input))
+    }
 
-    let zero_count = states.iter().filter(|&&pos| pos % GRID_SIZE == 0).count();
+    fn part1(commands: &Self::Input<'_>) -> Result<Answer> {
+        let (states, _) = all_states(commands);
+        Ok(states
+            .iter()
+            .filter(|&&pos| pos % GRID_SIZE == 0)
+            .count()
+            .into())
+    }
 
-    println!("p1: {}", zero_count);
-    println!("p2: {:?}", xx);
+    fn part2(commands: &Self::Input<'_>) -> Result<Answer> {
+        let (_, xx) = all_states(commands);
+        Ok(xx.into())
+    }
 }
 
 fn parse_commands(input: &str) -> IResult<&str, Vec<Command>> {
@@ -46,14 +59,17 @@ fn apply_command(position: i32, command: &Command) -> (i32, i32) {
     (position + diff, wraps)
 }
 
+/// Counts the clicks that land on zero while turning, including the last one
+/// but not the one the dial started on.
 fn zero_passes(position: i32, diff: i32) -> i32 {
     let start = position;
     let end = position + diff;
 
-    let pos_start = (start % GRID_SIZE + GRID_SIZE) % GRID_SIZE;
-    let pos_end = (end % GRID_SIZE + GRID_SIZE) % GRID_SIZE;
-
-    unimplemented!()
+    if diff >= 0 {
+        end.div_euclid(GRID_SIZE) - start.div_euclid(GRID_SIZE)
+    } else {
+        (start - 1).div_euclid(GRID_SIZE) - (end - 1).div_euclid(GRID_SIZE)
+    }
 }
 
 fn all_states(commands: &[Command]) -> (Vec<i32>, i32) {
@@ -67,6 +83,5 @@ fn all_states(commands: &[Command]) -> (Vec<i32>, i32) {
         position = res;
         states.push(position);
     }
-    println!("Number of wraps: {}", ctr);
     (states, ctr)
 }
@@ -0,0 +1,64 @@
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
+use nom::{IResult, Parser, bytes::complete::tag, character::complete::i64};
+
+#[derive(Debug)]
+pub struct Range {
+    start: i64,
+    end: i64,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Range>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1(ranges: &Self::Input<'_>) -> Result<Answer> {
+        Ok(ranges
+            .iter()
+            .map(|r| range_errors(r, true))
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(ranges: &Self::Input<'_>) -> Result<Answer> {
+        Ok(ranges
+            .iter()
+            .map(|r| range_errors(r, false))
+            .sum::<i64>()
+            .into())
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Range>> {
+    nom::multi::separated_list1(tag(","), range).parse(input)
+}
+
+fn range(input: &str) -> IResult<&str, Range> {
+    let (input, start) = i64(input)?;
+    let (input, _) = tag("-")(input)?;
+    let (input, end) = i64(input)?;
+    Ok((input, Range { start, end }))
+}
+
+fn range_errors(range: &Range, p1: bool) -> i64 {
+    (range.start..=range.end)
+        .map(|num| is_repeated_sequence(num, p1))
+        .sum()
+}
+
+fn is_repeated_sequence(num: i64, p1: bool) -> i64 {
+    let s = num.to_string();
+    let len = s.len();
+    let repeated = (1..(len / 2 + 1))
+        .filter(|i| len.is_multiple_of(*i) && (!p1 || len / i == 2))
+        .any(|i| {
+            let segment = &s[0..i];
+            segment.repeat(len / i) == s
+        });
+    repeated as i64 * num
+}
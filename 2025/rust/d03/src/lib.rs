@@ -0,0 +1,67 @@
+use std::cmp::max;
+
+use aoc_core::memo::Memo;
+use aoc_core::{Answer, AocError, Result, Solution};
+
+type Battery = Vec<i64>;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Battery>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(batteries: &Self::Input<'_>) -> Result<Answer> {
+        Ok(batteries
+            .iter()
+            .map(|battery| max_power(battery, 0, 2, &mut Memo::new()))
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(batteries: &Self::Input<'_>) -> Result<Answer> {
+        Ok(batteries
+            .iter()
+            .map(|battery| max_power(battery, 0, 12, &mut Memo::new()))
+            .sum::<i64>()
+            .into())
+    }
+}
+
+/// The memo is for a single battery, whose rest is then known by its length.
+fn max_power(
+    battery: &[i64],
+    choice: i64,
+    max_choices: i64,
+    memo: &mut Memo<(usize, i64), i64>,
+) -> i64 {
+    if battery.is_empty() || choice >= max_choices {
+        return 0;
+    }
+    memo.get_or_compute(&(battery.len(), choice), |memo| {
+        let current = battery[0];
+        let current_value = current * 10_i64.pow(choice as u32);
+        let picked = current_value + max_power(&battery[1..], choice + 1, max_choices, memo);
+        let not_picked = max_power(&battery[1..], choice, max_choices, memo);
+        max(picked, not_picked)
+    })
+}
+
+fn parse(input: &str) -> Result<Vec<Battery>> {
+    input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10).map(i64::from).ok_or_else(|| {
+                        AocError::at(input, &line[i..], format!("{:?} is not a joltage", c))
+                    })
+                })
+                .rev()
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+use aoc_core::{Answer, Result, Solution};
+use aoc_grid::{Grid, Pos};
+
+/// Whether each cell holds a roll of paper.
+type Map = Grid<bool>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(map).into())
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(map).into())
+    }
+}
+
+fn parse(input: &str) -> Result<Map> {
+    Grid::try_parse(input, |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+fn p2(map: &Map) -> i32 {
+    let mut count = 0;
+    let mut map = map.clone();
+
+    loop {
+        let mut tbr = vec![];
+        for (pos, _) in map.iter().filter(|(_, roll)| **roll) {
+            let adjacent = adjacent_chars(&map, pos);
+            if adjacent < 4 {
+                tbr.push(pos);
+            }
+        }
+        if tbr.is_empty() {
+            break;
+        }
+        for pos in tbr.iter() {
+            map[*pos] = false;
+        }
+        count += tbr.len() as i32;
+    }
+    count
+}
+
+fn p1(map: &Map) -> i32 {
+    let mut count = 0;
+    for (pos, _) in map.iter().filter(|(_, roll)| **roll) {
+        let adjacent = adjacent_chars(map, pos);
+        if adjacent < 4 {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn adjacent_chars(map: &Map, pos: Pos) -> i32 {
+    map.neighbours8(pos)
+        .filter(|&neighbor| map[neighbor])
+        .count() as i32
+}
@@ -1,5 +1,8 @@
 use std::collections::HashSet;
 
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
+
 use nom::bytes::tag;
 use nom::character::complete::{line_ending, u64};
 use nom::multi::separated_list1;
@@ -8,19 +11,27 @@ use nom::{IResult, Parser};
 
 type Rg = (u64, u64);
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_, (rgs, nums)) = parse(input).expect("Failed to parse input");
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = (Vec<Rg>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
 
-    let p1 = p1(&rgs, &nums);
-    let p2 = p2(&rgs);
+    fn part1((rgs, nums): &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(rgs, nums).into())
+    }
 
-    println!("Part 1: {}\nPart 2: {}", p1, p2);
+    fn part2((rgs, _): &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(rgs).into())
+    }
 }
 
-fn p2(rgs: &Vec<Rg>) -> u64 {
-    let mut rgs = rgs.clone();
-    rgs.sort_by(|&(a, _), &(b, _)| a.cmp(&b));
+fn p2(rgs: &[Rg]) -> u64 {
+    let mut rgs = rgs.to_vec();
+    rgs.sort_by_key(|&(a, _)| a);
 
     let max = rgs.len();
     let mut i = 0;
@@ -42,7 +53,7 @@ fn p2(rgs: &Vec<Rg>) -> u64 {
     rgs.iter()
         .enumerate()
         .filter_map(|(i, (s, e))| {
-            if (del.contains(&i)) {
+            if del.contains(&i)  {
                 None
             } else {
                 Some(e - s + 1)
@@ -51,8 +62,8 @@ fn p2(rgs: &Vec<Rg>) -> u64 {
         .sum()
 }
 
-fn p1(rgs: &Vec<Rg>, nums: &Vec<u64>) -> u64 {
-    nums.iter().filter(|n| in_any_range(&rgs, **n)).count() as u64
+fn p1(rgs: &[Rg], nums: &[u64]) -> u64 {
+    nums.iter().filter(|n| in_any_range(rgs, **n)).count() as u64
 }
 
 fn parse(input: &str) -> IResult<&str, (Vec<Rg>, Vec<u64>)> {
@@ -64,7 +75,7 @@ fn parse(input: &str) -> IResult<&str, (Vec<Rg>, Vec<u64>)> {
     .parse(input)
 }
 
-fn in_any_range(rgs: &Vec<Rg>, n: u64) -> bool {
+fn in_any_range(rgs: &[Rg], n: u64) -> bool {
     for (start, end) in rgs {
         if n >= *start && n <= *end {
             return true;
@@ -0,0 +1,146 @@
+use aoc_core::error::finish;
+use aoc_core::{Answer, AocError, Result, Solution};
+use nom::{
+    IResult, Parser,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{line_ending, multispace0, multispace1, space0, space1, u64},
+    combinator::{value, verify},
+    multi::separated_list1,
+    sequence::terminated,
+};
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add,
+    Mul,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        finish(input, p1(input)).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        let rotated = rotate(input);
+        // Each line of the rotated input is a column of the original.
+        finish(&rotated, p2(&rotated))
+            .map(Answer::from)
+            .map_err(|err| match err {
+                AocError::Parse {
+                    line,
+                    column,
+                    message,
+                } => AocError::Parse {
+                    line: column,
+                    column: line,
+                    message,
+                },
+                err => err,
+            })
+    }
+}
+
+/// Checks the worksheet is there and every line is as wide as the first, so
+/// its columns line up.
+fn parse(input: &str) -> Result<&str> {
+    let mut lines = input.lines();
+    let Some(width) = lines.next().map(|line| line.chars().count()) else {
+        return Err(AocError::at_end(input, "the worksheet is empty"));
+    };
+    for line in lines {
+        let len = line.chars().count();
+        if len != width {
+            return Err(AocError::at(
+                input,
+                line,
+                format!("row is {} wide, expected {}", len, width),
+            ));
+        }
+    }
+    Ok(input)
+}
+
+fn rotate(input: &str) -> String {
+    let lines: Vec<&str> = input.lines().collect();
+    let row_len = lines[0].len();
+    let mut rotated: Vec<Vec<char>> = vec![Vec::new(); row_len];
+    for line in lines {
+        for (i, c) in line.chars().enumerate() {
+            rotated[i].push(c);
+        }
+    }
+    let rotated: Vec<String> = rotated
+        .into_iter()
+        .map(|v| v.into_iter().collect())
+        .collect();
+
+    rotated.join("\n")
+}
+
+fn p2(input: &str) -> IResult<&str, u64> {
+    let (input, nums) =
+        terminated(separated_list1(multispace1, parse_rot_block), space0).parse(input)?;
+    Ok((input, nums.iter().sum()))
+}
+
+fn parse_rot_block(input: &str) -> IResult<&str, u64> {
+    let (rem, num1) = u64.parse(input)?;
+
+    let (rem, _) = space0(rem)?;
+    let (rem, op) = op(rem)?;
+    let (rem, _) = (space0, line_ending, space0).parse(rem)?;
+    let (rem, nums) = separated_list1((space0, line_ending, space0), u64).parse(rem)?;
+
+    match op {
+        Op::Add => Ok((rem, nums.iter().fold(num1, |acc, x| acc + x))),
+        Op::Mul => Ok((rem, nums.iter().fold(num1, |acc, x| acc * x))),
+    }
+}
+
+fn op(input: &str) -> IResult<&str, Op> {
+    alt((value(Op::Mul, tag("*")), value(Op::Add, tag("+")))).parse(input)
+}
+
+fn p1(input: &str) -> IResult<&str, u64> {
+    let (rem, nums) = separated_list1(multispace1, separated_list1(space1, u64)).parse(input)?;
+    let (rem, _) = multispace1.parse(rem)?;
+    let (rem, ops) = verify(
+        terminated(separated_list1(multispace1, op), multispace0),
+        |ops: &Vec<Op>| nums.iter().all(|row| row.len() == ops.len()),
+    )
+    .parse(rem)?;
+
+    let num = ops.iter().enumerate().fold(0, |acc, (i, op)| match op {
+        Op::Add => acc + nums.iter().map(|v| v[i]).sum::<u64>(),
+        Op::Mul => acc + nums.iter().map(|v| v[i]).product::<u64>(),
+    });
+    Ok((rem, num))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../fixtures/2025/06/example.txt");
+
+    #[test]
+    fn rejects_truncated_input() {
+        assert_eq!(
+            Day06::parse(&EXAMPLE[..36]).unwrap_err().to_string(),
+            "line 3, column 1: row is 4 wide, expected 15"
+        );
+        assert!(Day06::parse("").is_err());
+        let input = Day06::parse(&EXAMPLE[..32]).unwrap();
+        assert!(Day06::part1(&input).is_err());
+        assert!(Day06::part2(&input).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::VecDeque;
+
+use aoc_core::memo::Memo;
+use aoc_core::{Answer, AocError, Result, Solution};
+use aoc_grid::{Grid, Pos};
+use fxhash::FxHashSet;
+
+mod visualize;
+
+type Map = Grid<Cell>;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = (Map, Pos);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((map, start): &Self::Input<'_>) -> Result<Answer> {
+        Ok(sim(map, *start).into())
+    }
+
+    fn part2((map, start): &Self::Input<'_>) -> Result<Answer> {
+        Ok(all_paths_dp(map, *start, &mut Memo::new()).into())
+    }
+}
+
+pub enum Cell {
+    Empty,
+    Splitter,
+}
+
+fn parse(input: &str) -> Result<(Map, Pos)> {
+    let chars = Grid::try_parse(input, |ch| matches!(ch, '.' | 'S' | '^').then_some(ch))?;
+    let start = chars
+        .find(|&ch| ch == 'S')
+        .ok_or_else(|| AocError::at_end(input, "the manifold has no 'S'"))?;
+    let map = chars.map(|&ch| match ch {
+        '^' => Cell::Splitter,
+        _ => Cell::Empty,
+    });
+    Ok((map, start))
+}
+
+fn all_paths_dp(map: &Map, start: Pos, memo: &mut Memo<Pos, u64>) -> u64 {
+    memo.get_or_compute(&start, |memo| {
+        let mut total_paths = 0;
+        let (x, y) = start;
+        let next @ (nx, ny) = (x, y + 1);
+        match map.get(next) {
+            Some(Cell::Empty) => {
+                total_paths += all_paths_dp(map, next, memo);
+            }
+            Some(Cell::Splitter) => {
+                total_paths += all_paths_dp(map, (nx + 1, ny), memo);
+                total_paths += all_paths_dp(map, (nx - 1, ny), memo);
+            }
+            None => {
+                total_paths = 1;
+            }
+        }
+        total_paths
+    })
+}
+
+fn sim(map: &Map, start: Pos) -> u32 {
+    let mut visited = FxHashSet::default();
+    let mut queue = VecDeque::new();
+    let mut beams_reached = 0;
+    queue.push_back(start);
+    while let Some(pos) = queue.pop_front() {
+        if visited.contains(&pos) {
+            continue;
+        }
+        visited.insert(pos);
+        let (x, y) = pos;
+        let next @ (nx, ny) = (x, y + 1);
+        match map.get(next) {
+            Some(Cell::Empty) => {
+                queue.push_back(next);
+            }
+            Some(Cell::Splitter) => {
+                beams_reached += 1;
+                queue.push_back((nx + 1, ny));
+                queue.push_back((nx - 1, ny));
+            }
+            None => {}
+        }
+    }
+    beams_reached
+}
@@ -0,0 +1,79 @@
+use aoc_core::Result;
+use aoc_grid::{Grid, Pos};
+use aoc_vis::{Frame, Player, Render, Visualize};
+use fxhash::FxHashMap;
+
+use super::{Cell, Day07, Map};
+
+/// The beams down to row `y`, with how many timelines reach each cell they
+/// pass through.
+struct Beams<'a> {
+    map: &'a Map,
+    start: Pos,
+    timelines: Grid<u64>,
+    y: i32,
+    splits: u32,
+}
+
+impl Render for Beams<'_> {
+    fn render(&self) -> Frame {
+        let row_total: u64 = (0..self.timelines.width() as i32)
+            .map(|x| self.timelines[(x, self.y)])
+            .sum();
+        let mut frame = Frame::from_grid(self.map, |cell| match cell {
+            Cell::Empty => ('.', None),
+            Cell::Splitter => ('^', None),
+        })
+        .with_caption(format!(
+            "row {}, {} splits, {} timelines",
+            self.y, self.splits, row_total
+        ));
+        for (pos, &count) in self.timelines.iter() {
+            if count > 0 {
+                // Coloured by the number of digits, so the busier beams stand out.
+                frame.set(pos, '|', Some(count.ilog10() as usize));
+            }
+        }
+        frame.set(self.start, 'S', Some(0));
+        frame
+    }
+}
+
+impl Visualize for Day07 {
+    /// Moves the beams down a row per frame. Once they leave the manifold
+    /// the splits are part 1's answer and the timelines part 2's.
+    fn visualize((map, start): &Self::Input<'_>, player: &mut Player) -> Result<()> {
+        let mut beams = Beams {
+            map,
+            start: *start,
+            timelines: Grid::new(map.width(), map.height(), 0),
+            y: start.1,
+            splits: 0,
+        };
+        beams.timelines[*start] = 1;
+        while player.show(&beams) && beams.y + 1 < map.height() as i32 {
+            let mut next: FxHashMap<i32, u64> = FxHashMap::default();
+            for x in 0..map.width() as i32 {
+                let count = beams.timelines[(x, beams.y)];
+                if count == 0 {
+                    continue;
+                }
+                match map[(x, beams.y + 1)] {
+                    Cell::Empty => *next.entry(x).or_default() += count,
+                    Cell::Splitter => {
+                        beams.splits += 1;
+                        *next.entry(x - 1).or_default() += count;
+                        *next.entry(x + 1).or_default() += count;
+                    }
+                }
+            }
+            beams.y += 1;
+            for (x, count) in next {
+                if let Some(cell) = beams.timelines.get_mut((x, beams.y)) {
+                    *cell = count;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_core::error::finish;
+use aoc_core::{Answer, AocError, Params, Result, Solution};
+use disjoint::DisjointSet;
+use itertools::Itertools;
+use nom::{
+    IResult, Parser,
+    bytes::complete::tag,
+    character::complete::{i64, line_ending, multispace0},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::terminated,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+fn dist_sq(a: &Point, b: &Point) -> i64 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    let dz = a.z - b.z;
+    dx * dx + dy * dy + dz * dz
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Point>> {
+    all_consuming(terminated(
+        separated_list1(
+            line_ending,
+            (i64, tag(","), i64, tag(","), i64).map(|(x, _, y, _, z)| Point { x, y, z }),
+        ),
+        multispace0,
+    ))
+    .parse(input)
+}
+
+/// How many of the closest pairs part 1 connects.
+const CONNECTIONS: usize = 1000;
+
+/// The junction boxes, and how many pairs part 1 connects.
+pub struct Playground {
+    points: Vec<Point>,
+    connections: usize,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Playground;
+
+    const PARAMS: &[&str] = &["connections"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        Ok(Playground {
+            points: finish(input, parse(input))?,
+            connections: params.get("connections", CONNECTIONS)?,
+        })
+    }
+
+    fn part1(playground: &Self::Input<'_>) -> Result<Answer> {
+        let points = &playground.points;
+        let mut disjoint_set = DisjointSet::with_len(points.len());
+        for (_, a, b) in connections(points).into_iter().take(playground.connections) {
+            disjoint_set.join(a, b);
+        }
+        Ok(disjoint_set
+            .sets()
+            .iter()
+            .map(|s| s.len())
+            .sorted_unstable_by(|a, b| b.cmp(a))
+            .take(3)
+            .product::<usize>()
+            .into())
+    }
+
+    fn part2(playground: &Self::Input<'_>) -> Result<Answer> {
+        let points = &playground.points;
+        let mut disjoint_set = DisjointSet::with_len(points.len());
+        for (_, a, b) in connections(points) {
+            disjoint_set.join(a, b);
+            if disjoint_set.sets().len() == 1 {
+                return Ok((points[a].x * points[b].x).into());
+            }
+        }
+        Err(AocError::no_solution(
+            "the boxes never join into one circuit",
+        ))
+    }
+}
+
+fn connections(points: &[Point]) -> Vec<(i64, usize, usize)> {
+    let mut connections: Vec<_> = points
+        .iter()
+        .enumerate()
+        .combinations(2)
+        .map(|pair| {
+            let (i, p1) = pair[0];
+            let (j, p2) = pair[1];
+            let dist = dist_sq(p1, p2);
+            (dist, i, j)
+        })
+        .collect();
+    connections.sort_by_key(|(dist, _, _)| *dist);
+    connections
+}
@@ -1,3 +1,5 @@
+use aoc_core::error::finish;
+use aoc_core::{Answer, AocError, Result, Solution};
 use itertools::Itertools;
 use nom::bytes::complete::tag;
 use nom::character::complete::{i64, line_ending, multispace0};
@@ -8,7 +10,7 @@ use nom::{IResult, Parser};
 use rayon::prelude::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
+pub struct Point {
     x: i64,
     y: i64,
 }
@@ -49,26 +51,36 @@ fn area((p1, p2): &(Point, Point)) -> i64 {
     ((p1.x - p2.x).abs() + 1) * ((p1.y - p2.y).abs() + 1)
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_, points) = parse(input).unwrap();
+pub struct Day09;
 
-    let areas: Vec<_> = points
-        .clone()
-        .into_iter()
-        .tuple_combinations()
-        .map(|(p1, p2)| (p1, p2, area(&(p1, p2))))
-        .sorted_by_key(|&(_, _, area)| std::cmp::Reverse(area))
-        .collect();
+impl Solution for Day09 {
+    type Input<'a> = Vec<Point>;
 
-    let (_, _, p1) = areas[0];
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
 
-    let p2 = areas
-        .par_iter()
-        .find_first(|(p1, p2, _)| rectangle_inside(p1, p2, &points))
-        .map(|(_, _, area)| *area)
-        .unwrap();
+    fn part1(points: &Self::Input<'_>) -> Result<Answer> {
+        let (_, _, p1) = areas(points)[0];
+        Ok(p1.into())
+    }
 
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+    fn part2(points: &Self::Input<'_>) -> Result<Answer> {
+        areas(points)
+            .par_iter()
+            .find_first(|(p1, p2, _)| rectangle_inside(p1, p2, points))
+            .map(|(_, _, area)| *area)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::no_solution("no rectangle fits inside the loop"))
+    }
+}
+
+fn areas(points: &[Point]) -> Vec<(Point, Point, i64)> {
+    points
+        .iter()
+        .copied()
+        .tuple_combinations()
+        .map(|(p1, p2)| (p1, p2, area(&(p1, p2))))
+        .sorted_by_key(|&(_, _, area)| std::cmp::Reverse(area))
+        .collect()
 }
@@ -0,0 +1,78 @@
+mod parser;
+
+use aoc_core::error::finish;
+use aoc_core::{Answer, AocError};
+use good_lp::*;
+use itertools::Itertools;
+use parser::*;
+use rayon::prelude::*;
+
+pub struct Day10;
+
+impl aoc_core::Solution for Day10 {
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1(machines: &Self::Input<'_>) -> aoc_core::Result<Answer> {
+        machines
+            .par_iter()
+            .map(p1)
+            .sum::<Option<usize>>()
+            .map(Answer::from)
+            .ok_or_else(|| AocError::no_solution("a machine's lights cannot be matched"))
+    }
+
+    fn part2(machines: &Self::Input<'_>) -> aoc_core::Result<Answer> {
+        machines
+            .par_iter()
+            .map(p2)
+            .sum::<Option<usize>>()
+            .map(Answer::from)
+            .ok_or_else(|| AocError::no_solution("a machine's joltages cannot be matched"))
+    }
+}
+
+fn p1(machine: &Machine) -> Option<usize> {
+    let ind_len = machine.indicators.len();
+    let buttons_len = machine.buttons.len();
+    for num_combs in 1..=buttons_len {
+        for combo in machine.buttons.iter().combinations(num_combs) {
+            if (0..ind_len)
+                .all(|d| combo.iter().fold(0, |acc, b| acc ^ b[d]) == machine.indicators[d])
+            {
+                return Some(num_combs);
+            }
+        }
+    }
+    None
+}
+
+fn p2(machine: &Machine) -> Option<usize> {
+    let mut vars = ProblemVariables::new();
+
+    let mut presses = Vec::new();
+    for _ in 0..machine.buttons.len() {
+        let variable = vars.add(variable().min(0).integer());
+        presses.push(variable);
+    }
+
+    let mut problem = microlp(vars.minimise(presses.iter().sum::<Expression>()));
+
+    for d in 0..machine.joltage.len() {
+        let mut expr = Expression::from(0.0);
+        for (i, button) in machine.buttons.iter().enumerate() {
+            expr.add_mul(button[d] as f64, presses[i]);
+        }
+        problem.add_constraint(expr.eq(machine.joltage[d] as f64));
+    }
+    let sol = problem.solve().ok()?;
+    Some(
+        presses
+            .into_iter()
+            .map(|v| sol.value(v).round() as usize)
+            .sum(),
+    )
+}
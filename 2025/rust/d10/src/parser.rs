@@ -2,7 +2,7 @@ use nom::{
     IResult, Parser,
     branch::alt,
     character::complete::{char, i64, line_ending, multispace0},
-    combinator::{all_consuming, value},
+    combinator::{all_consuming, map_opt, value},
     multi::{many1, separated_list1},
     sequence::{delimited, terminated},
 };
@@ -25,18 +25,20 @@ fn indicators(input: &str) -> IResult<&str, Vec<i64>> {
 
 fn button(len: usize) -> impl Fn(&str) -> IResult<&str, Vec<i64>> {
     move |input: &str| {
-        delimited(
-            char('('),
-            separated_list1((char(','), multispace0), i64),
-            char(')'),
+        map_opt(
+            delimited(
+                char('('),
+                separated_list1((char(','), multispace0), i64),
+                char(')'),
+            ),
+            |btn: Vec<i64>| {
+                let mut zeroes = vec![0; len];
+                for &i in &btn {
+                    *zeroes.get_mut(usize::try_from(i).ok()?)? = 1;
+                }
+                Some(zeroes)
+            },
         )
-        .map(|btn: Vec<i64>| {
-            let mut zeroes = vec![0; len];
-            for i in &btn {
-                zeroes[*i as usize] = 1;
-            }
-            zeroes
-        })
         .parse(input)
     }
 }
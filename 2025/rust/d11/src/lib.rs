@@ -1,3 +1,6 @@
+use aoc_core::error::finish;
+use aoc_core::memo::Memo;
+use aoc_core::{Answer, Result, Solution};
 use fxhash::FxHashMap;
 use nom::{
     IResult, Parser,
@@ -8,14 +11,22 @@ use nom::{
     sequence::terminated,
 };
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_, hm) = parse(input).unwrap();
+pub struct Day11;
 
-    let part1 = solve(&hm, true);
-    let part2 = solve(&hm, false);
-    println!("Part 1: {}", part1);
-    println!("Part 1: {}", part2);
+impl Solution for Day11 {
+    type Input<'a> = Graph<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1(hm: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve(hm, true).into())
+    }
+
+    fn part2(hm: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve(hm, false).into())
+    }
 }
 
 type Graph<'a> = FxHashMap<&'a str, Vec<&'a str>>;
@@ -40,12 +51,12 @@ fn parse<'a>(input: &'a str) -> IResult<&'a str, Graph<'a>> {
 }
 
 fn solve(graph: &Graph, p1: bool) -> u64 {
-    type Memo<'a> = FxHashMap<(&'a str, bool, bool), u64>;
+    type Paths<'a> = Memo<(&'a str, bool, bool), u64>;
     fn dp<'a>(
         from: &'a str,
         to: &str,
         graph: &'a Graph,
-        memo: &mut Memo<'a>,
+        memo: &mut Paths<'a>,
         dac: bool,
         fft: bool,
     ) -> u64 {
@@ -56,24 +67,21 @@ fn solve(graph: &Graph, p1: bool) -> u64 {
         let dac = dac || from == "dac";
         let fft = fft || from == "fft";
 
-        if let Some(n) = memo.get(&(from, dac, fft)) {
-            return *n;
-        }
-
-        let mut acc = 0;
-        if let Some(adj) = graph.get(from) {
-            for next in adj {
-                acc += dp(next, to, graph, memo, dac, fft);
+        memo.get_or_compute(&(from, dac, fft), |memo| {
+            let mut acc = 0;
+            if let Some(adj) = graph.get(from) {
+                for next in adj {
+                    acc += dp(next, to, graph, memo, dac, fft);
+                }
             }
-        }
-        memo.insert((from, dac, fft), acc);
-        acc
+            acc
+        })
     }
     dp(
         if p1 { "you" } else { "svr" },
         "out",
         graph,
-        &mut Memo::default(),
+        &mut Paths::new(),
         p1,
         p1,
     )
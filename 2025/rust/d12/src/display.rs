@@ -1,3 +1,5 @@
+use aoc_image::Image;
+use aoc_vis::palette::{self, RESET};
 use fxhash::{FxHashMap, FxHashSet};
 
 fn solution_to_piece_id_matrix(
@@ -91,16 +93,9 @@ fn color_matrix_ids(matrix: &[Vec<usize>]) -> FxHashMap<usize, usize> {
     colors
 }
 
-const ANSI_COLORS: [&str; 9] = [
-    "\x1b[91m", "\x1b[92m", "\x1b[93m", "\x1b[94m", "\x1b[95m", "\x1b[96m", "\x1b[97m", "\x1b[31m",
-    "\x1b[32m",
-];
-const RESET: &str = "\x1b[0m";
-
 pub fn print_solution_colored(solution: &[Vec<usize>], grid_width: usize, grid_height: usize) {
     fn print_colored(color_id: usize, text: &str) {
-        let ansi_color = ANSI_COLORS[color_id % ANSI_COLORS.len()];
-        print!("{}{}{}", ansi_color, text, RESET);
+        print!("{}{}{}", palette::color(color_id), text, RESET);
     }
 
     let piece_id_matrix = solution_to_piece_id_matrix(solution, grid_width, grid_height);
@@ -130,3 +125,60 @@ pub fn print_solution_colored(solution: &[Vec<usize>], grid_width: usize, grid_h
     }
     println!("╯");
 }
+
+/// Colour of the border drawn around a packing, white.
+const BORDER: usize = 6;
+
+/// A picture of a packing with its pieces coloured as in
+/// `print_solution_colored`, each cell a `scale` pixel square, in a border.
+pub fn solution_image(
+    solution: &[Vec<usize>],
+    grid_width: usize,
+    grid_height: usize,
+    scale: usize,
+) -> Image {
+    let piece_id_matrix = solution_to_piece_id_matrix(solution, grid_width, grid_height);
+    let color_map = color_matrix_ids(&piece_id_matrix);
+    let packing = Image::from_cells(grid_width, grid_height, scale, |(x, y)| {
+        color_map
+            .get(&piece_id_matrix[y as usize][x as usize])
+            .copied()
+    });
+
+    let mut image = Image::new(packing.width() + 2, packing.height() + 2);
+    let (right, bottom) = (image.width() as i32 - 1, image.height() as i32 - 1);
+    for x in 0..=right {
+        image.set((x, 0), Some(BORDER));
+        image.set((x, bottom), Some(BORDER));
+    }
+    for y in 0..=bottom {
+        image.set((0, y), Some(BORDER));
+        image.set((right, y), Some(BORDER));
+    }
+    image.draw(&packing, (1, 1));
+    image
+}
+
+/// Lays `images` out left to right `gap` pixels apart, starting a new row
+/// before one would make the sheet wider than `max_width`.
+pub fn contact_sheet(images: &[Image], max_width: usize, gap: usize) -> Image {
+    let mut placed = Vec::with_capacity(images.len());
+    let (mut x, mut y, mut row_height, mut width) = (gap, gap, 0, gap);
+    for image in images {
+        if x > gap && x + image.width() + gap > max_width {
+            x = gap;
+            y += row_height + gap;
+            row_height = 0;
+        }
+        placed.push((x as i32, y as i32));
+        x += image.width() + gap;
+        row_height = row_height.max(image.height());
+        width = width.max(x);
+    }
+
+    let mut sheet = Image::new(width, y + row_height + gap);
+    for (image, &pos) in images.iter().zip(&placed) {
+        sheet.draw(image, pos);
+    }
+    sheet
+}
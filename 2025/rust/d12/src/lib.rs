@@ -1,14 +1,16 @@
+use aoc_core::error::finish;
+use aoc_core::{Answer, Result, Solution};
+use aoc_image::{Export, Image, Picture};
 use rayon::prelude::*;
 use xcov::{DlxBuilder, ExactCoverProblem, MrvExactCoverSearch};
 
-mod model;
+pub mod model;
 use model::*;
 
 mod parser;
 use parser::parse;
 
-mod display;
-use display::*;
+pub mod display;
 
 /*
 * Can be made very much faster by just determining
@@ -17,20 +19,41 @@ use display::*;
 * But this is pretty to look at.
 */
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_, instances) = parse(input).unwrap();
-    let solutions: Vec<_> = instances
-        .par_iter()
-        .filter_map(|instance| {
-            let (instance, solution) = solve(instance)?;
-            print_solution_colored(&solution, instance.grid_width, instance.grid_height);
-            println!();
-            Some((instance, solution))
-        })
-        .collect();
-    let ans = solutions.len();
-    println!("Total solutions found: {}", ans);
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<Instance>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        finish(input, parse(input))
+    }
+
+    fn part1(instances: &Self::Input<'_>) -> Result<Answer> {
+        Ok(instances.par_iter().filter_map(solve).count().into())
+    }
+}
+
+/// The pixels each cell of a region is drawn as.
+const SCALE: usize = 4;
+/// How wide the picture gets before the packings wrap onto a new row.
+const SHEET_WIDTH: usize = 1600;
+
+impl Export for Day12 {
+    /// Every region that has a packing, in order, with the packing found.
+    fn export<'a>(instances: &'a Self::Input<'_>) -> Result<Picture<'a>> {
+        let packings: Vec<Image> = instances
+            .par_iter()
+            .filter_map(solve)
+            .map(|(instance, solution)| {
+                display::solution_image(&solution, instance.grid_width, instance.grid_height, SCALE)
+            })
+            .collect();
+        Ok(Picture::Still(display::contact_sheet(
+            &packings,
+            SHEET_WIDTH,
+            SCALE,
+        )))
+    }
 }
 
 fn solve(instance: &Instance) -> Option<(Instance, Vec<Vec<usize>>)> {
@@ -87,3 +110,17 @@ fn solve(instance: &Instance) -> Option<(Instance, Vec<Vec<usize>>)> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../fixtures/2025/12/example.txt");
+
+    #[test]
+    fn solvable_regions() {
+        let instances = Day12::parse(EXAMPLE).unwrap();
+        assert!(solve(&instances[0]).is_some());
+        assert!(solve(&instances[1]).is_some());
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+
+/// A puzzle answer, shown exactly as it would be submitted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    /// Anything that is not a single number, e.g. a comma-joined program
+    /// output or a password.
+    Text(String),
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(text) => f.write_str(text),
+        }
+    }
+}
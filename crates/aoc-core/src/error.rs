@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::fmt;
+
+use nom::IResult;
+
+pub type Result<T, E = AocError> = std::result::Result<T, E>;
+
+/// Why a day could not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input does not have the puzzle's format. Lines and columns count
+    /// from 1, columns in characters.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input parsed, but has no answer, e.g. the goal is unreachable.
+    NoSolution(String),
+    /// A program in the input went wrong while running, at instruction
+    /// address `pc`.
+    Fault { pc: usize, message: String },
+    /// The part is not solved in code at all.
+    Unsolved(&'static str),
+    /// A [`Params`](crate::Params) value the day cannot use.
+    Param(String),
+}
+
+impl AocError {
+    /// A parse error at the start of `rest`, a slice of `input` such as the
+    /// remaining input nom reports an error at, or part of one of its lines.
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let (line, column) = position(input, rest);
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error for something missing from `input` altogether.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        AocError::NoSolution(message.into())
+    }
+}
+
+/// The line and column `rest` starts at within `input`. Anything that does not
+/// point into `input` is placed at its end.
+fn position(input: &str, rest: &str) -> (usize, usize) {
+    let offset = (rest.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset <= input.len() && input.is_char_boundary(offset))
+        .unwrap_or(input.len());
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Turns the result of running a nom parser over all of `input` into an
+/// [`AocError::Parse`] pointing at where it failed. Input the parser left
+/// over is an error too, unless it is only whitespace.
+pub fn finish<'a, T>(input: &'a str, result: IResult<&'a str, T>) -> Result<T> {
+    match result {
+        Ok((rest, _)) if !rest.trim().is_empty() => {
+            let rest = rest.trim_start();
+            Err(AocError::at(
+                input,
+                rest,
+                format!("unexpected {}", found(rest)),
+            ))
+        }
+        Ok((_, parsed)) => Ok(parsed),
+        Err(nom::Err::Incomplete(_)) => Err(AocError::at_end(input, "input ends too early")),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => Err(AocError::at(
+            input,
+            err.input,
+            format!(
+                "expected {}, found {}",
+                err.code.description(),
+                found(err.input)
+            ),
+        )),
+    }
+}
+
+/// The start of `rest` up to the end of its line, for error messages.
+fn found(rest: &str) -> String {
+    let line = rest.lines().next().unwrap_or_default();
+    match line.char_indices().nth(20) {
+        _ if line.is_empty() && rest.is_empty() => "end of input".to_string(),
+        _ if line.is_empty() => "end of line".to_string(),
+        Some((end, _)) => format!("{:?}...", &line[..end]),
+        None => format!("{:?}", line),
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            AocError::NoSolution(message) => write!(f, "no solution: {}", message),
+            AocError::Fault { pc, message } => write!(f, "fault at {}: {}", pc, message),
+            AocError::Unsolved(how) => write!(f, "not solved in code, {}", how),
+            AocError::Param(message) => write!(f, "bad parameter: {}", message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use nom::Parser;
+    use nom::character::complete::{digit1, newline};
+    use nom::multi::separated_list1;
+
+    use super::*;
+
+    fn numbers(input: &str) -> IResult<&str, Vec<&str>> {
+        separated_list1(newline, digit1).parse(input)
+    }
+
+    #[test]
+    fn points_at_the_failure() {
+        let input = "12\n34\nx5\n";
+        let err = finish(input, numbers(input)).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: unexpected \"x5\"");
+
+        let input = "12\n3é4x\n";
+        let err = AocError::at(input, &input[7..], "bad digit");
+        assert_eq!(err.to_string(), "line 2, column 4: bad digit");
+    }
+
+    #[test]
+    fn allows_trailing_whitespace() {
+        let input = "12\n34\n\n";
+        assert_eq!(finish(input, numbers(input)), Ok(vec!["12", "34"]));
+    }
+
+    #[test]
+    fn reports_what_was_expected() {
+        let input = "\nab";
+        let err = finish(input, numbers(input)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected Digit, found end of line"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The day's file in the inputs directory, `<dir>/<year>/<day>.txt`.
+    Default,
+    /// An explicit file, e.g. an example input or someone else's input.
+    Path(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl Source {
+    /// Parses a command line argument, where `-` means standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(path.into()),
+        }
+    }
+}
+
+/// The inputs directory, laid out as `<dir>/<year>/<day>.txt` with the day
+/// zero-padded, e.g. `inputs/2024/07.txt`.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub const DEFAULT_DIR: &str = "inputs";
+
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Inputs { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{:02}.txt", day))
+    }
+
+    pub fn load(&self, year: u16, day: u8, source: &Source) -> Result<String, InputError> {
+        match source {
+            Source::Default => {
+                let path = self.path(year, day);
+                if !path.exists() {
+                    return Err(InputError::Missing { year, day, path });
+                }
+                read(&path)
+            }
+            Source::Path(path) => read(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Inputs::new(Self::DEFAULT_DIR)
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The day has no file in the inputs directory.
+    Missing {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { year, day, path } => write!(
+                f,
+                "no input for {} day {}: {} does not exist \
+                 (save https://adventofcode.com/{}/day/{}/input there or pass --input)",
+                year,
+                day,
+                path.display(),
+                year,
+                day
+            ),
+            InputError::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            InputError::Stdin(source) => write!(f, "could not read stdin: {}", source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Io { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
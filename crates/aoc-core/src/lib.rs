@@ -0,0 +1,41 @@
+pub mod answer;
+pub mod error;
+pub mod input;
+pub mod memo;
+pub mod params;
+#[cfg(feature = "rng")]
+pub mod rng;
+
+pub use answer::Answer;
+pub use error::{AocError, Result};
+pub use params::Params;
+
+/// A single day of Advent of Code.
+///
+/// `parse` turns the raw puzzle input into whatever representation the day
+/// works on, and both parts are solved from that shared representation.
+/// Malformed input is reported as an [`AocError`] rather than a panic.
+pub trait Solution {
+    type Input<'a>;
+
+    /// The names of the [`Params`] `parse_with` reads, if any.
+    const PARAMS: &[&str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    /// Parses `input` for a puzzle whose constants differ from the real one,
+    /// like the examples. Only days that declare [`Solution::PARAMS`] need
+    /// to override it, and their `parse` should call it with
+    /// `Params::default()`.
+    fn parse_with<'a>(input: &'a str, _params: &Params) -> Result<Self::Input<'a>> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer>;
+
+    /// The last day of each year only has a single puzzle, so it has no
+    /// answer here.
+    fn part2(_input: &Self::Input<'_>) -> Result<Answer> {
+        Err(AocError::Unsolved("the last day has no part 2"))
+    }
+}
@@ -0,0 +1,83 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use fxhash::FxHashMap;
+
+/// A cache for memoized recursion.
+///
+/// Keys are looked up by anything they borrow as, and only turned into an
+/// owned `K` the first time a value is computed. `K` can itself borrow from
+/// the input, e.g. `Memo<&[char], u64>` keyed by slices of a pattern, so
+/// nothing is allocated per call.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: FxHashMap<K, V>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: FxHashMap::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value for `key`, computed by `f` the first time it is asked for.
+    /// `f` is handed the memo back so it can recurse through it.
+    ///
+    /// ```
+    /// # use aoc_core::memo::Memo;
+    /// fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+    ///     if n < 2 {
+    ///         return n;
+    ///     }
+    ///     memo.get_or_compute(&n, |memo| fib(n - 1, memo) + fib(n - 2, memo))
+    /// }
+    /// assert_eq!(fib(90, &mut Memo::new()), 2_880_067_194_370_816_120);
+    /// ```
+    pub fn get_or_compute<Q>(&mut self, key: &Q, f: impl FnOnce(&mut Self) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.cache.get(key) {
+            return value.clone();
+        }
+        let value = f(self);
+        self.cache.insert(key.to_owned(), value.clone());
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_each_key_once() {
+        let mut memo: Memo<String, usize> = Memo::new();
+        let mut calls = 0;
+        for word in ["ab", "abc", "ab"] {
+            let len = memo.get_or_compute(word, |_| {
+                calls += 1;
+                word.len()
+            });
+            assert_eq!(len, word.len());
+        }
+        assert_eq!(calls, 2);
+        assert_eq!(memo.len(), 2);
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use crate::{AocError, Result};
+
+/// Puzzle constants that are not part of the input, such as the size of the
+/// space the robots move in, which the examples shrink. Days fall back to the
+/// real puzzle's values for any they are not given.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: impl Into<String>, value: i64) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: i64) {
+        self.0.insert(name.into(), value);
+    }
+
+    /// The value of `name`, or `default` if it is not set.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str, default: T) -> Result<T> {
+        match self.0.get(name) {
+            None => Ok(default),
+            Some(&value) => T::try_from(value)
+                .map_err(|_| AocError::Param(format!("{} = {} is out of range", name, value))),
+        }
+    }
+
+    /// Fails on the first parameter that is not in `known`, so a misspelt
+    /// name is not silently replaced by its default.
+    pub fn check(&self, known: &[&str]) -> Result<()> {
+        match self.0.keys().find(|name| !known.contains(&name.as_str())) {
+            None => Ok(()),
+            Some(name) if known.is_empty() => Err(AocError::Param(format!(
+                "unknown parameter {:?}, the day takes none",
+                name
+            ))),
+            Some(name) => Err(AocError::Param(format!(
+                "unknown parameter {:?}, expected one of {}",
+                name,
+                known.join(", ")
+            ))),
+        }
+    }
+}
+
+impl FromIterator<(String, i64)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, i64)>>(iter: I) -> Self {
+        Params(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let params = Params::new().with("width", 11);
+        assert_eq!(params.get("width", 101), Ok(11));
+        assert_eq!(params.get("height", 103), Ok(103));
+        assert_eq!(
+            Params::new().with("bounds", -1).get("bounds", 70usize),
+            Err(AocError::Param("bounds = -1 is out of range".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        let params = Params::new().with("widht", 11);
+        assert_eq!(
+            params.check(&["width", "height"]).unwrap_err().to_string(),
+            "bad parameter: unknown parameter \"widht\", expected one of width, height"
+        );
+        assert!(Params::new().check(&[]).is_ok());
+    }
+}
@@ -0,0 +1,44 @@
+/// A seeded xorshift generator, for checks and tests that need numbers that
+/// look random but are the same on every run.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// A zero seed would only ever give zeros, so it is replaced by one.
+    pub fn new(seed: u64) -> Self {
+        Rng {
+            state: seed.max(1),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// A number below `n`, which must not be zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_from_the_same_seed() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..100 {
+            let n = a.below(10);
+            assert!(n < 10);
+            assert_eq!(n, b.below(10));
+        }
+        assert_ne!(Rng::new(0).next_u64(), 0);
+    }
+}
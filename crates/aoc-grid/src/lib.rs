@@ -0,0 +1,215 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use aoc_core::{AocError, Result};
+
+/// A position as `(x, y)`, with `y` growing downwards.
+pub type Pos = (i32, i32);
+
+/// Up, right, down, left. Clockwise, so turning right is `(dir + 1) % 4`.
+pub const DIRS: [Pos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Clockwise starting from up.
+pub const DIRS8: [Pos; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+pub fn step((x, y): Pos, (dx, dy): Pos) -> Pos {
+    (x + dx, y + dy)
+}
+
+/// The four positions next to `pos`, in `DIRS` order, without bounds checks.
+pub fn adjacent(pos: Pos) -> impl Iterator<Item = Pos> {
+    DIRS.into_iter().map(move |dir| step(pos, dir))
+}
+
+/// A dense rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Panics if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows differ in length"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Builds a grid from the lines of `input`, one cell per character.
+    /// Characters `cell` has no meaning for, and rows of the wrong length,
+    /// are errors.
+    pub fn try_parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, ch)| {
+                    cell(ch).ok_or_else(|| AocError::Parse {
+                        line: y + 1,
+                        column: x + 1,
+                        message: format!("unexpected {:?} in grid", ch),
+                    })
+                })
+                .collect::<Result<Vec<T>>>()?;
+            if let Some(first) = rows.first()
+                && row.len() != first.len()
+            {
+                return Err(AocError::Parse {
+                    line: y + 1,
+                    column: row.len().min(first.len()) + 1,
+                    message: format!("row is {} wide, expected {}", row.len(), first.len()),
+                });
+            }
+            rows.push(row);
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): Pos) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn offset(&self, pos: Pos) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| pos.1 as usize * self.width + pos.0 as usize)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The in-bounds orthogonal neighbours of `pos`, in `DIRS` order.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        adjacent(pos).filter(|&next| self.in_bounds(next))
+    }
+
+    /// The in-bounds neighbours of `pos` including diagonals, in `DIRS8` order.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS8
+            .into_iter()
+            .map(move |dir| step(pos, dir))
+            .filter(|&next| self.in_bounds(next))
+    }
+
+    /// The first position in row-major order whose cell matches.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Surrounds the grid with a one cell border of `pad`, so walking off the
+    /// edge lands on a sentinel instead of out of bounds. Every position moves
+    /// by `(1, 1)`.
+    pub fn padded(&self, pad: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut padded = Grid::new(self.width + 2, self.height + 2, pad);
+        for ((x, y), cell) in self.iter() {
+            padded[(x + 1, y + 1)] = cell.clone();
+        }
+        padded
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside the {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
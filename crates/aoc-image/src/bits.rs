@@ -0,0 +1,29 @@
+/// Packs values into bytes least significant bit first, the order both
+/// deflate and GIF's LZW use.
+#[derive(Default)]
+pub(crate) struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u64,
+    len: u32,
+}
+
+impl BitWriter {
+    /// Writes the low `count` bits of `value`.
+    pub(crate) fn bits(&mut self, value: u32, count: u32) {
+        self.acc |= u64::from(value) << self.len;
+        self.len += count;
+        while self.len >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    /// The bytes written, with the last one padded with zeros.
+    pub(crate) fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+        self.bytes
+    }
+}
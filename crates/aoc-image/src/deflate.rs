@@ -0,0 +1,195 @@
+use crate::bits::BitWriter;
+
+const WINDOW: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// How many earlier occurrences of the same three bytes to try.
+const MAX_CHAIN: usize = 32;
+const HASH_BITS: u32 = 15;
+const NONE: usize = usize::MAX;
+const END_OF_BLOCK: u32 = 256;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Compresses `data` as a zlib stream of a single deflate block with the
+/// fixed Huffman codes. Repeats are matched greedily, which is plenty for
+/// images made of flat blocks of colour.
+pub(crate) fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut out = BitWriter::default();
+    // Deflate with a 32K window, no dictionary, default level.
+    out.bits(0x78, 8);
+    out.bits(0x01, 8);
+    // The final block, with fixed codes.
+    out.bits(1, 1);
+    out.bits(1, 2);
+
+    let mut matcher = Matcher::new(data);
+    let mut i = 0;
+    while i < data.len() {
+        match matcher.longest(i) {
+            Some((len, dist)) => {
+                length(&mut out, len);
+                distance(&mut out, dist);
+                for pos in i..i + len {
+                    matcher.insert(pos);
+                }
+                i += len;
+            }
+            None => {
+                symbol(&mut out, u32::from(data[i]));
+                matcher.insert(i);
+                i += 1;
+            }
+        }
+    }
+    symbol(&mut out, END_OF_BLOCK);
+
+    let mut bytes = out.finish();
+    bytes.extend(adler32(data).to_be_bytes());
+    bytes
+}
+
+/// Finds earlier occurrences of the bytes at a position through chains of
+/// positions starting with the same three bytes.
+struct Matcher<'a> {
+    data: &'a [u8],
+    head: Vec<usize>,
+    prev: Vec<usize>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Matcher {
+            data,
+            head: vec![NONE; 1 << HASH_BITS],
+            prev: vec![NONE; data.len()],
+        }
+    }
+
+    fn hash(&self, pos: usize) -> Option<usize> {
+        let bytes = self.data.get(pos..pos + MIN_MATCH)?;
+        let key = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]);
+        Some((key.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize)
+    }
+
+    fn insert(&mut self, pos: usize) {
+        if let Some(hash) = self.hash(pos) {
+            self.prev[pos] = self.head[hash];
+            self.head[hash] = pos;
+        }
+    }
+
+    /// The longest match for the bytes at `pos` as its length and distance
+    /// back, if there is one long enough to encode.
+    fn longest(&self, pos: usize) -> Option<(usize, usize)> {
+        let mut candidate = self.head[self.hash(pos)?];
+        let max_len = MAX_MATCH.min(self.data.len() - pos);
+        let mut best: Option<(usize, usize)> = None;
+        for _ in 0..MAX_CHAIN {
+            if candidate == NONE || pos - candidate > WINDOW {
+                break;
+            }
+            let len = self.data[candidate..]
+                .iter()
+                .zip(&self.data[pos..pos + max_len])
+                .take_while(|(a, b)| a == b)
+                .count();
+            if len >= MIN_MATCH && best.is_none_or(|(best, _)| len > best) {
+                best = Some((len, pos - candidate));
+                if len == max_len {
+                    break;
+                }
+            }
+            candidate = self.prev[candidate];
+        }
+        best
+    }
+}
+
+/// Writes a literal/length symbol with its fixed Huffman code.
+fn symbol(out: &mut BitWriter, symbol: u32) {
+    let (code, len) = match symbol {
+        0..=143 => (0x30 + symbol, 8),
+        144..=255 => (0x190 + symbol - 144, 9),
+        256..=279 => (symbol - 256, 7),
+        _ => (0xc0 + symbol - 280, 8),
+    };
+    huffman(out, code, len);
+}
+
+fn length(out: &mut BitWriter, len: usize) {
+    let i = LENGTH_BASE.partition_point(|&base| usize::from(base) <= len) - 1;
+    symbol(out, 257 + i as u32);
+    out.bits(
+        (len - usize::from(LENGTH_BASE[i])) as u32,
+        u32::from(LENGTH_EXTRA[i]),
+    );
+}
+
+fn distance(out: &mut BitWriter, dist: usize) {
+    let i = DIST_BASE.partition_point(|&base| usize::from(base) <= dist) - 1;
+    huffman(out, i as u32, 5);
+    out.bits(
+        (dist - usize::from(DIST_BASE[i])) as u32,
+        u32::from(DIST_EXTRA[i]),
+    );
+}
+
+/// Huffman codes are packed starting from their most significant bit.
+fn huffman(out: &mut BitWriter, code: u32, len: u32) {
+    out.bits(code.reverse_bits() >> (32 - len), len);
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1, 0);
+    // Small enough chunks that the sums cannot overflow before reducing.
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(adler32(b""), 1);
+    }
+
+    #[test]
+    fn compresses_repeats() {
+        // As zlib decompresses it: three literals, then one match twelve
+        // long three back.
+        assert_eq!(
+            zlib(b"abcabcabcabcabc"),
+            [
+                0x78, 0x01, 0x4b, 0x4c, 0x4a, 0x46, 0x46, 0x00, 0x2d, 0xf5, 0x05, 0xbf
+            ]
+        );
+        let rows = [0u8, 1, 1, 1, 2, 2, 2, 2].repeat(1000);
+        assert!(zlib(&rows).len() < 100);
+    }
+}
@@ -0,0 +1,164 @@
+use std::io::{self, Write};
+
+use fxhash::FxHashMap;
+
+use crate::bits::BitWriter;
+use crate::{COLORS, Image};
+
+/// The global colour table holds `1 << TABLE_BITS` colours, the palette
+/// padded with black.
+const TABLE_BITS: u8 = 4;
+const MAX_CODES: u16 = 4096;
+
+/// Writes the frames of an animation as a looping GIF, each shown for
+/// `delay` hundredths of a second.
+pub(crate) struct GifWriter<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    pub(crate) fn new(mut out: W, width: usize, height: usize, delay: u16) -> io::Result<Self> {
+        let (w, h) = (dimension(width)?, dimension(height)?);
+        out.write_all(b"GIF89a")?;
+        out.write_all(&w.to_le_bytes())?;
+        out.write_all(&h.to_le_bytes())?;
+        // A global colour table with 8 bits per primary, then the background
+        // colour and no aspect ratio.
+        out.write_all(&[0xf0 | (TABLE_BITS - 1), 0, 0])?;
+        let mut table = COLORS.concat();
+        table.resize(3 << TABLE_BITS, 0);
+        out.write_all(&table)?;
+        // The NETSCAPE2.0 extension, looping forever.
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+        Ok(GifWriter {
+            out,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub(crate) fn frame(&mut self, image: &Image) -> io::Result<()> {
+        if (image.width, image.height) != (self.width, self.height) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "a {}x{} frame in a {}x{} animation",
+                    image.width, image.height, self.width, self.height
+                ),
+            ));
+        }
+        // A graphic control extension for the delay, leaving the frame in
+        // place, then the image descriptor for the whole screen.
+        self.out.write_all(&[0x21, 0xf9, 0x04, 0x04])?;
+        self.out.write_all(&self.delay.to_le_bytes())?;
+        self.out.write_all(&[0, 0, 0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&(self.width as u16).to_le_bytes())?;
+        self.out.write_all(&(self.height as u16).to_le_bytes())?;
+        self.out.write_all(&[0, TABLE_BITS])?;
+        for block in lzw(TABLE_BITS, &image.pixels).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+        self.out.write_all(&[0])
+    }
+
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()
+    }
+}
+
+fn dimension(size: usize) -> io::Result<u16> {
+    u16::try_from(size).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} pixels is too big for a GIF", size),
+        )
+    })
+}
+
+/// Compresses palette indices of `min_size` bits with GIF's variant of LZW.
+fn lzw(min_size: u8, indices: &[u8]) -> Vec<u8> {
+    let clear = 1u16 << min_size;
+    let end = clear + 1;
+    let mut out = BitWriter::default();
+    let mut codes: FxHashMap<(u16, u8), u16> = FxHashMap::default();
+    let mut size = u32::from(min_size) + 1;
+    let mut next = end + 1;
+    out.bits(clear.into(), size);
+
+    let mut indices = indices.iter();
+    let Some(&first) = indices.next() else {
+        out.bits(end.into(), size);
+        return out.finish();
+    };
+    let mut prefix = u16::from(first);
+    for &index in indices {
+        if let Some(&code) = codes.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+        out.bits(prefix.into(), size);
+        if next < MAX_CODES {
+            codes.insert((prefix, index), next);
+            next += 1;
+            // The decoder adds each code a step behind, so it only needs
+            // the wider codes once the one after this has been added.
+            if u32::from(next) > 1 << size {
+                size += 1;
+            }
+        } else {
+            out.bits(clear.into(), size);
+            codes.clear();
+            size = u32::from(min_size) + 1;
+            next = end + 1;
+        }
+        prefix = index.into();
+    }
+    out.bits(prefix.into(), size);
+    out.bits(end.into(), size);
+    out.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The sample image from "What's In A GIF", a 10 by 10 grid in three
+    /// colours, and its image data as compressed by the usual encoders.
+    #[test]
+    fn compresses_like_other_encoders() {
+        let rows = [
+            "1111122222",
+            "1111122222",
+            "1111122222",
+            "1110000222",
+            "1110000222",
+            "2220000111",
+            "2220000111",
+            "2222211111",
+            "2222211111",
+            "2222211111",
+        ];
+        let indices: Vec<u8> = rows.concat().bytes().map(|b| b - b'0').collect();
+        assert_eq!(
+            lzw(2, &indices),
+            [
+                0x8c, 0x2d, 0x99, 0x87, 0x2a, 0x1c, 0xdc, 0x33, 0xa0, 0x02, 0x75, 0xec, 0x95, 0xfa,
+                0xa8, 0xde, 0x60, 0x8c, 0x04, 0x91, 0x4c, 0x01
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_frames_of_another_size() {
+        let mut gif = GifWriter::new(Vec::new(), 2, 2, 10).unwrap();
+        gif.frame(&Image::new(2, 2)).unwrap();
+        assert!(gif.frame(&Image::new(3, 2)).is_err());
+        gif.finish().unwrap();
+    }
+}
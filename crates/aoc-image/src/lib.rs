@@ -0,0 +1,245 @@
+mod bits;
+mod deflate;
+mod gif;
+mod png;
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use aoc_core::{Result, Solution};
+use aoc_grid::{Grid, Pos};
+
+use gif::GifWriter;
+
+pub type Rgb = [u8; 3];
+
+pub const BACKGROUND: Rgb = [0, 0, 0];
+
+/// The colours of the terminal palette in `aoc_vis::palette`, in the shades
+/// most terminals show them in, so pictures match what `--visualize` draws.
+pub const PALETTE: [Rgb; 9] = [
+    [255, 85, 85],
+    [85, 255, 85],
+    [255, 255, 85],
+    [85, 85, 255],
+    [255, 85, 255],
+    [85, 255, 255],
+    [255, 255, 255],
+    [170, 0, 0],
+    [0, 170, 0],
+];
+
+/// The colours pixels index into, the background first.
+const COLORS: [Rgb; 10] = {
+    let mut colors = [BACKGROUND; 10];
+    let mut i = 0;
+    while i < PALETTE.len() {
+        colors[i + 1] = PALETTE[i];
+        i += 1;
+    }
+    colors
+};
+
+/// A picture whose pixels are either background or one of the [`PALETTE`]
+/// colours. Like a `Frame` in the terminal, colours are given as `None` for
+/// the background or `Some(id)`, cycling through the palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// An image of only background.
+    pub fn new(width: usize, height: usize) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![0; width * height],
+        }
+    }
+
+    /// A picture of a `width` by `height` grid of cells, each drawn as a
+    /// `scale` pixel square in the colour `color` gives it.
+    pub fn from_cells(
+        width: usize,
+        height: usize,
+        scale: usize,
+        mut color: impl FnMut(Pos) -> Option<usize>,
+    ) -> Self {
+        let mut image = Image::new(width * scale, height * scale);
+        for y in 0..height {
+            for x in 0..width {
+                let index = index(color((x as i32, y as i32)));
+                for row in y * scale..(y + 1) * scale {
+                    let start = row * image.width + x * scale;
+                    image.pixels[start..start + scale].fill(index);
+                }
+            }
+        }
+        image
+    }
+
+    /// A picture of `grid` with every cell drawn as in [`Image::from_cells`].
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        scale: usize,
+        mut color: impl FnMut(&T) -> Option<usize>,
+    ) -> Self {
+        Image::from_cells(grid.width(), grid.height(), scale, |pos| color(&grid[pos]))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Colours the pixel at `pos`, or nothing if it is outside the image.
+    pub fn set(&mut self, (x, y): Pos, color: Option<usize>) {
+        if (0..self.width as i32).contains(&x) && (0..self.height as i32).contains(&y) {
+            self.pixels[y as usize * self.width + x as usize] = index(color);
+        }
+    }
+
+    /// Copies `image` in with its top left corner at `pos`, clipped to this
+    /// one.
+    pub fn draw(&mut self, image: &Image, (left, top): Pos) {
+        for (y, row) in image.pixels.chunks(image.width.max(1)).enumerate() {
+            for (x, &pixel) in row.iter().enumerate() {
+                let (x, y) = (left + x as i32, top + y as i32);
+                if (0..self.width as i32).contains(&x) && (0..self.height as i32).contains(&y) {
+                    self.pixels[y as usize * self.width + x as usize] = pixel;
+                }
+            }
+        }
+    }
+
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        png::write(self, out)
+    }
+}
+
+/// The index into [`COLORS`] of a colour.
+fn index(color: Option<usize>) -> u8 {
+    color.map_or(0, |id| (id % PALETTE.len() + 1) as u8)
+}
+
+/// Frames of the same size played one after another.
+pub struct Animation<'a> {
+    delay: u16,
+    frames: Box<dyn Iterator<Item = Image> + 'a>,
+}
+
+impl<'a> Animation<'a> {
+    /// An animation showing each frame for `delay` hundredths of a second.
+    /// The frames are only made as they are written.
+    pub fn new(delay: u16, frames: impl IntoIterator<Item = Image> + 'a) -> Self {
+        Animation {
+            delay,
+            frames: Box::new(frames.into_iter()),
+        }
+    }
+}
+
+/// What a day exports: a still image, written as a PNG, an animation,
+/// written as a GIF, or a graph in Graphviz's DOT language for `dot` to lay
+/// out.
+pub enum Picture<'a> {
+    Still(Image),
+    Animation(Animation<'a>),
+    Graph(String),
+}
+
+impl Picture<'_> {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Picture::Still(_) => "png",
+            Picture::Animation(_) => "gif",
+            Picture::Graph(_) => "dot",
+        }
+    }
+
+    pub fn write(self, mut out: impl Write) -> io::Result<()> {
+        match self {
+            Picture::Still(image) => image.write_png(out),
+            Picture::Graph(dot) => out.write_all(dot.as_bytes()),
+            Picture::Animation(Animation { delay, mut frames }) => {
+                let Some(first) = frames.next() else {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "the animation has no frames",
+                    ));
+                };
+                let mut gif = GifWriter::new(out, first.width, first.height, delay)?;
+                gif.frame(&first)?;
+                for frame in frames {
+                    gif.frame(&frame)?;
+                }
+                gif.finish()
+            }
+        }
+    }
+
+    /// Writes the picture to `path`, which must have the extension for its
+    /// format.
+    pub fn save(self, path: &Path) -> io::Result<()> {
+        let extension = self.extension();
+        if path.extension().is_none_or(|ext| ext != extension) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("expected a .{} file", extension),
+            ));
+        }
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write(&mut out)?;
+        out.flush()
+    }
+}
+
+/// A [`Solution`] that can be exported as a picture with `aoc run --export`.
+pub trait Export: Solution {
+    fn export<'a>(input: &'a Self::Input<'_>) -> Result<Picture<'a>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_cells() {
+        let grid = Grid::from_rows(vec![vec![false, true], vec![true, false]]);
+        let mut image = Image::from_grid(&grid, 2, |&on| on.then_some(0));
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(
+            image.pixels,
+            [0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0]
+        );
+
+        image.draw(&Image::from_cells(1, 1, 2, |_| Some(10)), (3, 3));
+        image.set((0, 0), Some(1));
+        assert_eq!(
+            image.pixels,
+            [2, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 2]
+        );
+    }
+
+    #[test]
+    fn saves_by_extension() {
+        let still = Picture::Still(Image::new(1, 1));
+        assert_eq!(still.extension(), "png");
+        let err = still.save(Path::new("still.gif")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        let mut gif = Vec::new();
+        Picture::Animation(Animation::new(5, (0..3).map(|_| Image::new(2, 1))))
+            .write(&mut gif)
+            .unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(gif.last(), Some(&0x3b));
+    }
+}
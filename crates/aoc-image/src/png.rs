@@ -0,0 +1,97 @@
+use std::io::{self, Write};
+
+use crate::deflate::zlib;
+use crate::{COLORS, Image};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+/// Palette-indexed pixels.
+const INDEXED: u8 = 3;
+
+/// Writes `image` as an 8 bit palette-indexed PNG.
+pub(crate) fn write(image: &Image, mut out: impl Write) -> io::Result<()> {
+    out.write_all(&SIGNATURE)?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend((image.width as u32).to_be_bytes());
+    header.extend((image.height as u32).to_be_bytes());
+    // Bit depth, colour type, then the default compression, filtering and
+    // no interlacing.
+    header.extend([8, INDEXED, 0, 0, 0]);
+    chunk(&mut out, b"IHDR", &header)?;
+
+    chunk(&mut out, b"PLTE", &COLORS.concat())?;
+
+    // Every row starts with its filter type, none.
+    let mut raw = Vec::with_capacity((image.width + 1) * image.height);
+    for row in image.pixels.chunks(image.width.max(1)) {
+        raw.push(0);
+        raw.extend(row);
+    }
+    chunk(&mut out, b"IDAT", &zlib(&raw))?;
+
+    chunk(&mut out, b"IEND", &[])
+}
+
+fn chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    !bytes.into_iter().fold(!0, |crc, &byte| {
+        CRC_TABLE[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn writes_the_chunks() {
+        let mut image = Image::new(3, 2);
+        image.set((1, 1), Some(0));
+        let mut png = Vec::new();
+        write(&image, &mut png).unwrap();
+
+        assert_eq!(png[..8], SIGNATURE);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(png[16..29], [0, 0, 0, 3, 0, 0, 0, 2, 8, 3, 0, 0, 0]);
+        assert_eq!(&png[37..41], b"PLTE");
+        assert_eq!(
+            png[png.len() - 12..],
+            [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]
+        );
+    }
+}
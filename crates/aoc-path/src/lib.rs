@@ -0,0 +1,315 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use fxhash::{FxHashMap, FxHashSet};
+
+/// What a search can add up as the cost of a path. Zero is `Default`.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Which predecessors a search remembers for each node it reaches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preds {
+    /// The first one found, enough for a single shortest path.
+    One,
+    /// Every one a shortest path comes through, so that together they form
+    /// the DAG of all shortest paths.
+    All,
+}
+
+/// The result of a search: the cost of every node reached and how it was
+/// reached.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    costs: FxHashMap<N, C>,
+    preds: FxHashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Paths<N, C> {
+    fn new() -> Self {
+        Paths {
+            costs: FxHashMap::default(),
+            preds: FxHashMap::default(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// The goals reached at the lowest cost, in the order they were found.
+    /// Searches stop once nothing cheaper is left, so they are all the goals
+    /// a shortest path ends at.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The cost of the shortest path to a goal, if one was reached.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.cost(goal))
+    }
+
+    /// The cost of the cheapest path found to `node`. Only final for nodes
+    /// the search got to before it stopped, such as the goals.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// A shortest path from a start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(pred) = self.preds.get(path.last()?).and_then(|preds| preds.first()) {
+            path.push(pred.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on a shortest path from a start to one of `ends`. With
+    /// [`Preds::One`] that is only the nodes of the paths [`Paths::path`]
+    /// gives.
+    pub fn on_shortest_paths<'a>(&'a self, ends: impl IntoIterator<Item = &'a N>) -> FxHashSet<N> {
+        let mut seen = FxHashSet::default();
+        let mut stack: Vec<&N> = ends
+            .into_iter()
+            .filter(|end| self.costs.contains_key(end))
+            .collect();
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(self.preds.get(node).into_iter().flatten());
+            }
+        }
+        seen
+    }
+
+    fn start(&mut self, node: N) {
+        self.costs.insert(node, C::default());
+    }
+
+    /// Records reaching `next` from `node` at `cost`, returning whether that
+    /// is cheaper than any way found before.
+    fn relax(&mut self, node: &N, next: N, cost: C, preds: Preds) -> bool {
+        match self.costs.get(&next) {
+            Some(&old) if old < cost => false,
+            Some(&old) if old == cost => {
+                if preds == Preds::All
+                    && let Some(known) = self.preds.get_mut(&next)
+                {
+                    known.push(node.clone());
+                }
+                false
+            }
+            _ => {
+                self.costs.insert(next.clone(), cost);
+                self.preds.insert(next, vec![node.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth-first search over unit cost steps, from every node in `starts`
+/// until the nearest nodes `goal` accepts are reached, or everything
+/// reachable if it accepts none.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+    preds: Preds,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.costs.contains_key(&start) {
+            paths.start(start.clone());
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, cost)) = queue.pop_front() {
+        if paths.goal_cost().is_some_and(|best| cost > best) {
+            break;
+        }
+        if goal(&node) {
+            paths.goals.push(node);
+            continue;
+        }
+        for next in neighbours(&node) {
+            if paths.relax(&node, next.clone(), cost + 1, preds) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm, with `neighbours` giving each step's cost. Stops
+/// like [`bfs`].
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+    preds: Preds,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), goal, preds)
+}
+
+/// A*, guided by `heuristic`, which must never overestimate the cost left
+/// to a goal and should be consistent for [`Preds::All`] to find every
+/// shortest path. Stops like [`bfs`].
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+    preds: Preds,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut done = FxHashSet::default();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        paths.start(start.clone());
+        heap.push(State {
+            estimate: heuristic(&start),
+            cost: C::default(),
+            node: start,
+        });
+    }
+    while let Some(State {
+        estimate,
+        cost,
+        node,
+    }) = heap.pop()
+    {
+        if paths.goal_cost().is_some_and(|best| estimate > best) {
+            break;
+        }
+        if paths.cost(&node).is_some_and(|best| best < cost) || !done.insert(node.clone()) {
+            continue;
+        }
+        if goal(&node) {
+            paths.goals.push(node);
+            continue;
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if paths.relax(&node, next.clone(), next_cost, preds) {
+                heap.push(State {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    paths
+}
+
+/// A node on the heap, cheapest estimate first.
+struct State<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 5 by 5 grid walled off at x = 2 except for y = 0 and y = 4.
+    fn open(&(x, y): &(i32, i32)) -> bool {
+        (0..5).contains(&x) && (0..5).contains(&y) && (x != 2 || y == 0 || y == 4)
+    }
+
+    fn steps(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(open)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_finds_every_shortest_path() {
+        let paths = bfs([(0, 2)], steps, |&pos| pos == (4, 2), Preds::All);
+        assert_eq!(paths.goal_cost(), Some(8));
+        let path = paths.path(&(4, 2)).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!((path[0], path[8]), ((0, 2), (4, 2)));
+        // Around the top or the bottom of the wall, and on either side any
+        // way that does not double back.
+        assert_eq!(paths.on_shortest_paths(paths.goals()).len(), 22);
+
+        let paths = bfs([(0, 2)], steps, |&pos| pos == (4, 2), Preds::One);
+        assert_eq!(paths.on_shortest_paths(paths.goals()).len(), 9);
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        // Stepping onto the bottom row costs 5, so only the way over the top
+        // is shortest.
+        let weighted = |pos: &(i32, i32)| {
+            steps(pos)
+                .into_iter()
+                .map(|next| (next, if next.1 == 4 { 5 } else { 1 }))
+        };
+        let goal = |&pos: &(i32, i32)| pos == (4, 2);
+        let plain = dijkstra([(0, 2)], weighted, goal, Preds::All);
+        let guided = astar(
+            [(0, 2)],
+            weighted,
+            |&(x, y)| (4 - x).abs() + (2 - y).abs(),
+            goal,
+            Preds::All,
+        );
+        assert_eq!(plain.goal_cost(), Some(8));
+        assert_eq!(guided.goal_cost(), Some(8));
+        for paths in [&plain, &guided] {
+            let top = paths.on_shortest_paths(paths.goals());
+            assert_eq!(top.len(), 13);
+            assert!(top.iter().all(|&(_, y)| y <= 2));
+        }
+    }
+
+    #[test]
+    fn unreachable_goals() {
+        let paths = bfs([(0, 2)], steps, |&pos| pos == (9, 9), Preds::One);
+        assert_eq!(paths.goal_cost(), None);
+        assert_eq!(paths.cost(&(4, 4)), Some(6));
+        assert!(paths.path(&(9, 9)).is_none());
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+use aoc_grid::{Grid, Pos};
+
+use crate::palette::{self, RESET};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    ch: char,
+    color: Option<usize>,
+}
+
+const BLANK: Cell = Cell {
+    ch: ' ',
+    color: None,
+};
+
+/// A picture of a simulation at one moment: a caption above a grid of
+/// characters, each either uncoloured or in one of the [`palette`] colours.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    caption: String,
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    keep_bottom: bool,
+}
+
+impl Frame {
+    /// A blank frame.
+    pub fn new(width: usize, height: usize) -> Self {
+        Frame {
+            caption: String::new(),
+            width,
+            height,
+            cells: vec![BLANK; width * height],
+            keep_bottom: false,
+        }
+    }
+
+    /// A frame with a character for every cell of `grid`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut cell: impl FnMut(&T) -> (char, Option<usize>)) -> Self {
+        let mut frame = Frame::new(grid.width(), grid.height());
+        for (pos, value) in grid.iter() {
+            let (ch, color) = cell(value);
+            frame.set(pos, ch, color);
+        }
+        frame
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Keeps the bottom rows rather than the top ones when the frame is too
+    /// tall for the terminal, for simulations that happen at the bottom.
+    pub fn keep_bottom(mut self) -> Self {
+        self.keep_bottom = true;
+        self
+    }
+
+    /// Draws `ch` at `pos`, or nothing if it is outside the frame.
+    pub fn set(&mut self, (x, y): Pos, ch: char, color: Option<usize>) {
+        if (0..self.width as i32).contains(&x) && (0..self.height as i32).contains(&y) {
+            self.cells[y as usize * self.width + x as usize] = Cell { ch, color };
+        }
+    }
+
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The rows that fit in `rows` lines.
+    pub(crate) fn visible_rows(&self, rows: usize) -> Range<usize> {
+        let rows = rows.min(self.height);
+        if self.keep_bottom {
+            self.height - rows..self.height
+        } else {
+            0..rows
+        }
+    }
+
+    /// The first `columns` characters of row `y`, with escape codes for their
+    /// colours if `color` is set.
+    pub(crate) fn line(&self, y: usize, columns: usize, color: bool) -> String {
+        let row = &self.cells[y * self.width..][..self.width.min(columns)];
+        let mut line = String::new();
+        let mut current = None;
+        for cell in row {
+            if color && cell.color != current {
+                match cell.color {
+                    Some(id) => line.push_str(palette::color(id)),
+                    None => line.push_str(RESET),
+                }
+                current = cell.color;
+            }
+            line.push(cell.ch);
+        }
+        if current.is_some() {
+            line.push_str(RESET);
+        }
+        line
+    }
+}
+
+/// The caption and rows as plain text, without colours.
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        for y in 0..self.height {
+            writeln!(f, "{}", self.line(y, self.width, false))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_cells_in_colour() {
+        let grid = Grid::from_rows(vec![vec![true, false, false], vec![false, false, true]]);
+        let mut frame =
+            Frame::from_grid(&grid, |&wall| if wall { ('#', None) } else { ('.', None) })
+                .with_caption("t = 1");
+        frame.set((1, 1), '@', Some(0));
+        frame.set((2, 1), '@', Some(0));
+        frame.set((3, 0), '@', Some(1));
+
+        assert_eq!(frame.to_string(), "t = 1\n#..\n.@@\n");
+        assert_eq!(frame.line(0, 3, true), "#..");
+        assert_eq!(
+            frame.line(1, 3, true),
+            format!(".{}@@{}", palette::color(0), RESET)
+        );
+        assert_eq!(frame.line(1, 2, false), ".@");
+    }
+
+    #[test]
+    fn crops_to_the_top_or_bottom() {
+        let frame = Frame::new(2, 5);
+        assert_eq!(frame.visible_rows(3), 0..3);
+        assert_eq!(frame.visible_rows(9), 0..5);
+        assert_eq!(frame.keep_bottom().visible_rows(3), 2..5);
+    }
+}
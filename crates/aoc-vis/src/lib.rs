@@ -0,0 +1,20 @@
+mod frame;
+pub mod palette;
+mod player;
+
+pub use frame::Frame;
+pub use player::Player;
+
+use aoc_core::{Result, Solution};
+
+/// Simulation state that can be drawn.
+pub trait Render {
+    fn render(&self) -> Frame;
+}
+
+/// A [`Solution`] whose simulation can be watched with `aoc run --visualize`.
+pub trait Visualize: Solution {
+    /// Plays the simulation on `input` frame by frame until it ends or
+    /// [`Player::show`] says the viewer has quit.
+    fn visualize(input: &Self::Input<'_>, player: &mut Player) -> Result<()>;
+}
@@ -0,0 +1,10 @@
+pub const ANSI_COLORS: [&str; 9] = [
+    "\x1b[91m", "\x1b[92m", "\x1b[93m", "\x1b[94m", "\x1b[95m", "\x1b[96m", "\x1b[97m", "\x1b[31m",
+    "\x1b[32m",
+];
+pub const RESET: &str = "\x1b[0m";
+
+/// The escape code for colour `id`, cycling through the palette.
+pub fn color(id: usize) -> &'static str {
+    ANSI_COLORS[id % ANSI_COLORS.len()]
+}
@@ -0,0 +1,246 @@
+use std::io::{self, Stdout, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use termion::cursor::HideCursor;
+use termion::event::Key;
+use termion::input::{Keys, TermRead};
+use termion::raw::{IntoRawMode, RawTerminal};
+use termion::screen::{AlternateScreen, IntoAlternateScreen};
+use termion::{AsyncReader, async_stdin, clear, cursor};
+
+use crate::Render;
+
+const MAX_FPS: u32 = 1024;
+const HELP: &str = "space: pause, n: step, +/-: speed, q: quit";
+
+type Screen = HideCursor<AlternateScreen<RawTerminal<Stdout>>>;
+
+enum Output {
+    /// Frames are drawn over each other on the alternate screen, with the
+    /// keyboard controlling playback.
+    Terminal {
+        screen: Screen,
+        keys: Keys<AsyncReader>,
+    },
+    /// Frames are written one after another as plain text, as fast as they
+    /// come.
+    Plain(Box<dyn Write>),
+}
+
+/// Plays frames of a simulation.
+///
+/// In a terminal the frames are animated: space pauses and resumes, `n` or
+/// the right arrow steps a paused simulation one frame forward, `+` and `-`
+/// double and halve the speed, and `q` quits. Anywhere else, such as when
+/// the output is piped to a file, every frame is written out in turn.
+pub struct Player {
+    output: Output,
+    fps: u32,
+    paused: bool,
+    finished: bool,
+    frames: usize,
+    quit: bool,
+}
+
+impl Player {
+    /// Plays on the terminal at `fps` frames a second, or writes to stdout
+    /// if it is not a terminal.
+    pub fn new(fps: u32) -> io::Result<Self> {
+        let stdout = io::stdout();
+        if !termion::is_tty(&stdout) {
+            return Ok(Player::plain(stdout, fps));
+        }
+        let screen = HideCursor::from(stdout.into_raw_mode()?.into_alternate_screen()?);
+        Ok(Player::with_output(
+            Output::Terminal {
+                screen,
+                keys: async_stdin().keys(),
+            },
+            fps,
+        ))
+    }
+
+    /// Writes every frame to `out` as plain text.
+    pub fn plain(out: impl Write + 'static, fps: u32) -> Self {
+        Player::with_output(Output::Plain(Box::new(out)), fps)
+    }
+
+    fn with_output(output: Output, fps: u32) -> Self {
+        Player {
+            output,
+            fps: fps.clamp(1, MAX_FPS),
+            paused: false,
+            finished: false,
+            frames: 0,
+            quit: false,
+        }
+    }
+
+    /// Renders `scene` and shows it for one frame, or until the viewer steps
+    /// on if paused. Returns `false` once the viewer has quit or the output
+    /// is gone, and the simulation should stop.
+    pub fn show(&mut self, scene: &impl Render) -> bool {
+        if self.quit {
+            return false;
+        }
+        let frame = scene.render();
+        self.frames += 1;
+        let shown = match &mut self.output {
+            Output::Plain(out) => writeln!(out, "{}", frame).is_ok(),
+            Output::Terminal { screen, .. } => draw(screen, &frame).is_ok() && self.wait(),
+        };
+        self.quit = !shown;
+        shown
+    }
+
+    /// How many frames have been shown.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Keeps the last frame on screen until the viewer quits, unless they
+    /// already have.
+    pub fn finish(&mut self) {
+        if self.quit || matches!(self.output, Output::Plain(_)) {
+            return;
+        }
+        self.paused = true;
+        self.finished = true;
+        while self.wait() {}
+        self.quit = true;
+    }
+
+    /// Waits out the current frame while handling keys. Returns `false` if
+    /// the viewer quits.
+    fn wait(&mut self) -> bool {
+        let Output::Terminal { screen, keys } = &mut self.output else {
+            return true;
+        };
+        let shown = Instant::now();
+        if status(
+            screen,
+            state(self.finished, self.paused),
+            self.fps,
+            self.frames,
+        )
+        .is_err()
+        {
+            return false;
+        }
+        loop {
+            for key in keys.by_ref() {
+                match key {
+                    Ok(Key::Char('q') | Key::Esc | Key::Ctrl('c')) | Err(_) => return false,
+                    Ok(Key::Char(' ')) => self.paused = !self.paused,
+                    Ok(Key::Char('n') | Key::Right) if self.paused => return true,
+                    Ok(Key::Char('+' | '=') | Key::Up) => self.fps = (self.fps * 2).min(MAX_FPS),
+                    Ok(Key::Char('-') | Key::Down) => self.fps = (self.fps / 2).max(1),
+                    Ok(_) => continue,
+                }
+                if status(
+                    screen,
+                    state(self.finished, self.paused),
+                    self.fps,
+                    self.frames,
+                )
+                .is_err()
+                {
+                    return false;
+                }
+            }
+            let delay = Duration::from_secs(1) / self.fps;
+            if !self.paused && shown.elapsed() >= delay {
+                return true;
+            }
+            thread::sleep(delay.min(Duration::from_millis(10)));
+        }
+    }
+}
+
+/// Draws `frame` over the previous one, cropped to the terminal.
+fn draw(screen: &mut Screen, frame: &crate::Frame) -> io::Result<()> {
+    let (columns, rows) = termion::terminal_size()?;
+    // The caption and the status line take a row each.
+    let rows = frame.visible_rows((rows as usize).saturating_sub(2));
+    write!(
+        screen,
+        "{}{}{}",
+        clear::All,
+        cursor::Goto(1, 1),
+        frame.caption()
+    )?;
+    for y in rows {
+        write!(screen, "\r\n{}", frame.line(y, columns as usize, true))?;
+    }
+    screen.flush()
+}
+
+fn state(finished: bool, paused: bool) -> &'static str {
+    match (finished, paused) {
+        (true, _) => "finished",
+        (false, true) => "paused",
+        (false, false) => "playing",
+    }
+}
+
+fn status(screen: &mut Screen, state: &str, fps: u32, frames: usize) -> io::Result<()> {
+    let (_, rows) = termion::terminal_size()?;
+    write!(
+        screen,
+        "{}{}frame {}, {} at {} fps ({})",
+        cursor::Goto(1, rows),
+        clear::CurrentLine,
+        frames,
+        state,
+        fps,
+        HELP
+    )?;
+    screen.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::Frame;
+
+    /// A writer whose output can still be read after the player owns it.
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct Counter(usize);
+
+    impl Render for Counter {
+        fn render(&self) -> Frame {
+            let mut frame = Frame::new(3, 1).with_caption(format!("step {}", self.0));
+            frame.set((self.0 as i32, 0), '*', Some(self.0));
+            frame
+        }
+    }
+
+    #[test]
+    fn plain_output_writes_every_frame() {
+        let out = Shared::default();
+        let mut player = Player::plain(out.clone(), 10);
+        for step in 0..3 {
+            assert!(player.show(&Counter(step)));
+        }
+        player.finish();
+        assert_eq!(player.frames(), 3);
+        let text = String::from_utf8(out.0.borrow().clone()).unwrap();
+        assert_eq!(text, "step 0\n*  \n\nstep 1\n * \n\nstep 2\n  *\n\n");
+    }
+}
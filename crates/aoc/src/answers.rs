@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_core::input::Inputs;
+
+/// Identifies an answer: the same day and part can have several answers, one
+/// for each input it was run on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub hash: u64,
+}
+
+/// Known answers, kept in `answers.txt` in the inputs directory as one
+/// `<year> <day> <part> <input hash> <answer>` per line. Blank lines and
+/// lines starting with `#` are ignored.
+pub struct Store {
+    path: PathBuf,
+    answers: BTreeMap<Key, String>,
+}
+
+impl Store {
+    pub const FILE: &str = "answers.txt";
+
+    /// Loads the store from the inputs directory. A missing file is an empty
+    /// store.
+    pub fn load(inputs: &Inputs) -> Result<Self, String> {
+        let path = inputs.dir().join(Self::FILE);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("could not read {}: {}", path.display(), err)),
+        };
+        let mut answers = BTreeMap::new();
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, answer) = parse_line(line).ok_or_else(|| {
+                format!("malformed line {} in {}: {}", n + 1, path.display(), line)
+            })?;
+            answers.insert(key, answer.to_string());
+        }
+        Ok(Store { path, answers })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.answers.get(key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: Key, answer: String) {
+        self.answers.insert(key, answer);
+    }
+
+    /// Writes every answer back, sorted by year, day and part.
+    pub fn save(&self) -> Result<(), String> {
+        let mut text = String::from("# <year> <day> <part> <input hash> <answer>\n");
+        for (key, answer) in &self.answers {
+            text += &format!(
+                "{} {} {} {:016x} {}\n",
+                key.year, key.day, key.part, key.hash, answer
+            );
+        }
+        fs::write(&self.path, text)
+            .map_err(|err| format!("could not write {}: {}", self.path.display(), err))
+    }
+}
+
+fn parse_line(line: &str) -> Option<(Key, &str)> {
+    let fields: Vec<&str> = line.splitn(5, ' ').collect();
+    let [year, day, part, hash, answer] = fields[..] else {
+        return None;
+    };
+    let key = Key {
+        year: year.parse().ok()?,
+        day: day.parse().ok()?,
+        part: part.parse().ok()?,
+        hash: u64::from_str_radix(hash, 16).ok()?,
+    };
+    Some((key, answer))
+}
+
+/// A stable hash of a puzzle input, so answers stay tied to the input they
+/// were found for. Trailing whitespace is ignored, since editors and
+/// downloads disagree on the final newline.
+pub fn hash(input: &str) -> u64 {
+    // 64-bit FNV-1a.
+    input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_round_trip() {
+        let key = Key {
+            year: 2024,
+            day: 17,
+            part: 1,
+            hash: hash("Register A: 729\n"),
+        };
+        let line = format!("2024 17 1 {:016x} 4,6,3,5,6,3,5,2,1,0", key.hash);
+        assert_eq!(parse_line(&line), Some((key, "4,6,3,5,6,3,5,2,1,0")));
+        assert_eq!(parse_line("2024 17 1 4,6,3"), None);
+    }
+
+    #[test]
+    fn hash_ignores_the_final_newline() {
+        assert_eq!(hash("1\n2"), hash("1\n2\n"));
+        assert_ne!(hash("1\n2"), hash("2\n1"));
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::days::{Day, Timings};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    /// The name used in JSON and CSV output.
+    fn id(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part 1",
+            Phase::Part2 => "part 2",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+/// The timings of one phase of one day, in the shape saved for baselines.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Record {
+    fn new(day: &Day, phase: Phase, samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        let n = nanos.len();
+        let median = if n.is_multiple_of(2) {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2
+        } else {
+            nanos[n / 2]
+        };
+        Record {
+            year: day.year,
+            day: day.day,
+            phase,
+            iterations: n,
+            min_ns: nanos[0],
+            median_ns: median,
+            mean_ns: nanos.iter().sum::<u64>() / n as u64,
+        }
+    }
+
+    fn key(&self) -> (u16, u8, Phase) {
+        (self.year, self.day, self.phase)
+    }
+}
+
+/// Summarises every phase of a day, `None` for the ones that failed. Parts
+/// that are not solved in code are left out.
+pub fn summarise(day: &Day, timings: &Timings) -> Vec<(Phase, Option<Record>)> {
+    [
+        (Phase::Parse, &timings.parse),
+        (Phase::Part1, &timings.part1),
+        (Phase::Part2, &timings.part2),
+    ]
+    .into_iter()
+    .filter(|(_, samples)| samples.as_ref().is_none_or(|samples| !samples.is_empty()))
+    .map(|(phase, samples)| {
+        let record = samples
+            .as_deref()
+            .map(|samples| Record::new(day, phase, samples));
+        (phase, record)
+    })
+    .collect()
+}
+
+/// Median timings from an earlier run saved with `--save`.
+pub struct Baseline {
+    medians: HashMap<(u16, u8, Phase), u64>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+        let records: Vec<Record> = serde_json::from_str(&json)
+            .map_err(|err| format!("could not parse {}: {}", path.display(), err))?;
+        let medians = records.iter().map(|r| (r.key(), r.median_ns)).collect();
+        Ok(Baseline { medians })
+    }
+
+    /// How much slower the median got, in percent. Negative if it got faster.
+    pub fn change(&self, record: &Record) -> Option<f64> {
+        let &old = self.medians.get(&record.key())?;
+        if old == 0 {
+            return None;
+        }
+        Some((record.median_ns as f64 - old as f64) / old as f64 * 100.0)
+    }
+}
+
+pub fn print_row(phase: Phase, record: Option<&Record>, change: Option<f64>, threshold: f64) {
+    let Some(record) = record else {
+        println!("  {:<8}failed", phase);
+        return;
+    };
+    print!(
+        "  {:<8}min {:>10}  median {:>10}  mean {:>10}",
+        phase,
+        nanos(record.min_ns),
+        nanos(record.median_ns),
+        nanos(record.mean_ns)
+    );
+    match change {
+        Some(change) if change > threshold => println!("  {:+.1}% regressed", change),
+        Some(change) => println!("  {:+.1}%", change),
+        None => println!(),
+    }
+}
+
+fn nanos(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("records serialize")
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from("year,day,phase,iterations,min_ns,median_ns,mean_ns\n");
+    for r in records {
+        csv += &format!(
+            "{},{},{},{},{},{},{}\n",
+            r.year,
+            r.day,
+            r.phase.id(),
+            r.iterations,
+            r.min_ns,
+            r.median_ns,
+            r.mean_ns
+        );
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::DAYS;
+
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let samples = [40, 10, 30, 20].map(Duration::from_nanos);
+        let record = Record::new(&DAYS[0], Phase::Part1, &samples);
+        assert_eq!(record.iterations, 4);
+        assert_eq!(record.min_ns, 10);
+        assert_eq!(record.median_ns, 25);
+        assert_eq!(record.mean_ns, 25);
+
+        let record = Record::new(&DAYS[0], Phase::Part1, &samples[..3]);
+        assert_eq!(record.median_ns, 30);
+    }
+
+    #[test]
+    fn json_and_csv_name_phases_alike() {
+        let records = [Record::new(
+            &DAYS[0],
+            Phase::Part2,
+            &[Duration::from_nanos(5)],
+        )];
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records)).unwrap();
+        assert_eq!(json[0]["phase"], "part2");
+        assert!(to_csv(&records).contains(",part2,"));
+    }
+}
@@ -0,0 +1,347 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Answer, AocError, Params, Solution};
+use aoc_image::Export;
+use aoc_vis::{Player, Visualize};
+
+type Answers = Vec<(u8, Result<Answer, Failure>)>;
+type Visualizer = fn(&str, &Params, &mut Player) -> Result<(), Failure>;
+type Exporter = fn(&str, &Params, &Path) -> Result<(), String>;
+
+/// Why a day or one of its parts has no answer.
+#[derive(Debug, Clone)]
+pub enum Failure {
+    Error(AocError),
+    Panicked,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(err) => write!(f, "{}", err),
+            Failure::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+/// How long each phase took on every iteration. A phase that failed has no
+/// timings, and neither do the parts if parsing failed. A part that is not
+/// solved in code has an empty list.
+pub struct Timings {
+    pub parse: Option<Vec<Duration>>,
+    pub part1: Option<Vec<Duration>>,
+    pub part2: Option<Vec<Duration>>,
+}
+
+/// A registered day, with its solution erased behind a function pointer so
+/// every year can live in one table.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str, &Params, &[u8]) -> Result<Answers, Failure>,
+    bench: fn(&str, usize) -> Timings,
+    visualize: Option<Visualizer>,
+    export: Option<Exporter>,
+}
+
+impl Day {
+    const fn new<S: Solution>(year: u16, day: u8) -> Self {
+        Day {
+            year,
+            day,
+            solve: solve::<S>,
+            bench: bench::<S>,
+            visualize: None,
+            export: None,
+        }
+    }
+
+    /// Lets the day's simulation be watched with `--visualize`.
+    const fn visual<S: Visualize>(self) -> Self {
+        Day {
+            visualize: Some(visualize::<S>),
+            ..self
+        }
+    }
+
+    /// Lets the day be drawn to an image file with `--export`.
+    const fn exported<S: Export>(self) -> Self {
+        Day {
+            export: Some(export::<S>),
+            ..self
+        }
+    }
+
+    /// Runs the requested parts on `input`, or fails if it cannot be parsed
+    /// or `params` has one the day does not take.
+    pub fn solve(&self, input: &str, params: &Params, parts: &[u8]) -> Result<Answers, Failure> {
+        (self.solve)(input, params, parts)
+    }
+
+    /// Parses `input` and solves both parts `iterations` times, timing each
+    /// phase separately.
+    pub fn bench(&self, input: &str, iterations: usize) -> Timings {
+        (self.bench)(input, iterations)
+    }
+
+    pub fn has_visualization(&self) -> bool {
+        self.visualize.is_some()
+    }
+
+    /// Plays the day's simulation of `input` on `player`, or `None` if it
+    /// has none.
+    pub fn visualize(
+        &self,
+        input: &str,
+        params: &Params,
+        player: &mut Player,
+    ) -> Option<Result<(), Failure>> {
+        self.visualize
+            .map(|visualize| visualize(input, params, player))
+    }
+
+    /// Writes the day's picture of `input` to `path`, or `None` if it has
+    /// none.
+    pub fn export(&self, input: &str, params: &Params, path: &Path) -> Option<Result<(), String>> {
+        self.export.map(|export| export(input, params, path))
+    }
+}
+
+/// Runs `f`, treating a panic like an error.
+fn catch<T>(f: impl FnOnce() -> aoc_core::Result<T>) -> Result<T, Failure> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(Failure::Error),
+        Err(_) => Err(Failure::Panicked),
+    }
+}
+
+fn solve<S: Solution>(input: &str, params: &Params, parts: &[u8]) -> Result<Answers, Failure> {
+    let input = catch(|| {
+        params.check(S::PARAMS)?;
+        S::parse_with(input, params)
+    })?;
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let answer = catch(|| match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            });
+            (part, answer)
+        })
+        .collect())
+}
+
+fn visualize<S: Visualize>(
+    input: &str,
+    params: &Params,
+    player: &mut Player,
+) -> Result<(), Failure> {
+    catch(|| {
+        params.check(S::PARAMS)?;
+        S::visualize(&S::parse_with(input, params)?, player)
+    })
+}
+
+fn export<S: Export>(input: &str, params: &Params, path: &Path) -> Result<(), String> {
+    let input = catch(|| {
+        params.check(S::PARAMS)?;
+        S::parse_with(input, params)
+    })
+    .map_err(|failure| format!("could not parse the input: {}", failure))?;
+    // Frames are drawn as they are written, so writing can panic as well.
+    catch(|| Ok(S::export(&input)?.save(path)))
+        .map_err(|failure| format!("could not export: {}", failure))?
+        .map_err(|err| format!("could not write {}: {}", path.display(), err))
+}
+
+fn bench<S: Solution>(input: &str, iterations: usize) -> Timings {
+    let mut timings = Timings {
+        parse: Some(Vec::with_capacity(iterations)),
+        part1: Some(Vec::with_capacity(iterations)),
+        part2: Some(Vec::with_capacity(iterations)),
+    };
+    for _ in 0..iterations {
+        let Some(parsed) = time(&mut timings.parse, || S::parse(black_box(input))) else {
+            timings.part1 = None;
+            timings.part2 = None;
+            break;
+        };
+        time(&mut timings.part1, || S::part1(&parsed));
+        time(&mut timings.part2, || S::part2(&parsed));
+    }
+    timings
+}
+
+/// Runs `f` and records how long it took, unless an earlier run failed or
+/// it is not solved in code.
+fn time<T>(
+    samples: &mut Option<Vec<Duration>>,
+    f: impl FnOnce() -> aoc_core::Result<T>,
+) -> Option<T> {
+    let durations = samples.as_mut()?;
+    let start = Instant::now();
+    match catch(f) {
+        Ok(out) => {
+            durations.push(start.elapsed());
+            Some(black_box(out))
+        }
+        Err(Failure::Error(AocError::Unsolved(_))) => {
+            durations.clear();
+            None
+        }
+        Err(_) => {
+            *samples = None;
+            None
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<aoc_2023_d22::Day22>(2023, 22).visual::<aoc_2023_d22::Day22>(),
+    Day::new::<aoc_2023_d23::Day23>(2023, 23),
+    Day::new::<aoc_2024_d01::Day01>(2024, 1),
+    Day::new::<aoc_2024_d02::Day02>(2024, 2),
+    Day::new::<aoc_2024_d03::Day03>(2024, 3),
+    Day::new::<aoc_2024_d04::Day04>(2024, 4),
+    Day::new::<aoc_2024_d05::Day05>(2024, 5),
+    Day::new::<aoc_2024_d06::Day06>(2024, 6).visual::<aoc_2024_d06::Day06>(),
+    Day::new::<aoc_2024_d07::Day07>(2024, 7),
+    Day::new::<aoc_2024_d08::Day08>(2024, 8),
+    Day::new::<aoc_2024_d09::Day09>(2024, 9),
+    Day::new::<aoc_2024_d10::Day10>(2024, 10),
+    Day::new::<aoc_2024_d11::Day11>(2024, 11),
+    Day::new::<aoc_2024_d12::Day12>(2024, 12),
+    Day::new::<aoc_2024_d13::Day13>(2024, 13),
+    Day::new::<aoc_2024_d14::Day14>(2024, 14)
+        .visual::<aoc_2024_d14::Day14>()
+        .exported::<aoc_2024_d14::Day14>(),
+    Day::new::<aoc_2024_d15::Day15>(2024, 15).visual::<aoc_2024_d15::Day15>(),
+    Day::new::<aoc_2024_d16::Day16>(2024, 16).exported::<aoc_2024_d16::Day16>(),
+    Day::new::<aoc_2024_d17::Day17>(2024, 17),
+    Day::new::<aoc_2024_d18::Day18>(2024, 18),
+    Day::new::<aoc_2024_d19::Day19>(2024, 19),
+    Day::new::<aoc_2024_d20::Day20>(2024, 20),
+    Day::new::<aoc_2024_d21::Day21>(2024, 21).visual::<aoc_2024_d21::Day21>(),
+    Day::new::<aoc_2024_d22::Day22>(2024, 22),
+    Day::new::<aoc_2024_d23::Day23>(2024, 23),
+    Day::new::<aoc_2024_d24::Day24>(2024, 24).exported::<aoc_2024_d24::Day24>(),
+    Day::new::<aoc_2024_d25::Day25>(2024, 25),
+    Day::new::<aoc_2025_d01::Day01>(2025, 1),
+    Day::new::<aoc_2025_d02::Day02>(2025, 2),
+    Day::new::<aoc_2025_d03::Day03>(2025, 3),
+    Day::new::<aoc_2025_d04::Day04>(2025, 4),
+    Day::new::<aoc_2025_d05::Day05>(2025, 5),
+    Day::new::<aoc_2025_d06::Day06>(2025, 6),
+    Day::new::<aoc_2025_d07::Day07>(2025, 7).visual::<aoc_2025_d07::Day07>(),
+    Day::new::<aoc_2025_d08::Day08>(2025, 8),
+    Day::new::<aoc_2025_d09::Day09>(2025, 9),
+    Day::new::<aoc_2025_d10::Day10>(2025, 10),
+    Day::new::<aoc_2025_d11::Day11>(2025, 11),
+    Day::new::<aoc_2025_d12::Day12>(2025, 12).exported::<aoc_2025_d12::Day12>(),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use aoc_core::input::{Inputs, Source};
+    use aoc_image::{Animation, Image, Picture};
+
+    use super::*;
+    use crate::answers::{self, Key, Store};
+
+    fn inputs() -> Inputs {
+        match env::var_os("AOC_INPUTS") {
+            Some(dir) => Inputs::new(dir),
+            None => Inputs::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../inputs")),
+        }
+    }
+
+    /// Checks the real inputs against the answers recorded in `answers.txt`
+    /// by `aoc verify --update`. Neither is committed, so this only runs when
+    /// asked for with `--ignored` or `--include-ignored`.
+    #[test]
+    #[ignore = "needs the real inputs and inputs/answers.txt"]
+    fn real_answers() {
+        let inputs = inputs();
+        let store = Store::load(&inputs).unwrap_or_else(|err| panic!("{}", err));
+        assert!(
+            store.path().exists(),
+            "{} does not exist",
+            store.path().display()
+        );
+
+        let mut failures = Vec::new();
+        for day in DAYS {
+            // Days without an input are skipped rather than failed.
+            let Ok(input) = inputs.load(day.year, day.day, &Source::Default) else {
+                continue;
+            };
+            let hash = answers::hash(&input);
+            for part in [1, 2] {
+                let key = Key {
+                    year: day.year,
+                    day: day.day,
+                    part,
+                    hash,
+                };
+                let Some(expected) = store.get(&key) else {
+                    continue;
+                };
+                let answer = day
+                    .solve(&input, &Params::default(), &[part])
+                    .and_then(|mut answers| answers.remove(0).1);
+                match answer {
+                    Ok(answer) if answer.to_string() == expected => {}
+                    Ok(answer) => failures.push(format!(
+                        "{} day {:02} part {}: expected {}, got {}",
+                        day.year, day.day, part, expected, answer
+                    )),
+                    Err(failure) => failures.push(format!(
+                        "{} day {:02} part {}: {}",
+                        day.year, day.day, part, failure
+                    )),
+                }
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    /// Draws a frame that panics only once the animation is written.
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input<'a> = ();
+
+        fn parse(_input: &str) -> aoc_core::Result<()> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> aoc_core::Result<Answer> {
+            Ok(Answer::from(0))
+        }
+    }
+
+    impl Export for Broken {
+        fn export<'a>(_input: &'a ()) -> aoc_core::Result<Picture<'a>> {
+            let frames = (0..2).map(|i| match i {
+                0 => Image::from_cells(1, 1, 1, |_| Some(0)),
+                _ => panic!("no second frame"),
+            });
+            Ok(Picture::Animation(Animation::new(1, frames)))
+        }
+    }
+
+    #[test]
+    fn export_catches_panics_while_writing() {
+        let path = env::temp_dir().join(format!("aoc-broken-{}.gif", std::process::id()));
+        let result = export::<Broken>("", &Params::default(), &path);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(result, Err("could not export: panicked".to_string()));
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::{Answer, Params};
+use serde::Deserialize;
+
+use crate::days::{Day, Failure};
+
+pub const DEFAULT_DIR: &str = "fixtures";
+
+/// An input together with the parameters it is meant to be solved with and
+/// the answers it should give, kept in `<dir>/<year>/<day>/` as a
+/// `<name>.toml` next to the `<name>.txt` it is for:
+///
+/// ```toml
+/// # Only for fixtures that share another one's input.
+/// input = "example.txt"
+/// # For fixtures that take too long to check in unoptimised builds.
+/// slow = true
+///
+/// [params]
+/// width = 11
+/// height = 7
+///
+/// [answers]
+/// part1 = 12
+/// ```
+///
+/// Parts without an answer are not checked.
+pub struct Fixture {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub input: String,
+    pub params: Params,
+    pub answers: Vec<(u8, String)>,
+    pub slow: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Spec {
+    input: Option<String>,
+    #[serde(default)]
+    slow: bool,
+    #[serde(default)]
+    params: BTreeMap<String, i64>,
+    #[serde(default)]
+    answers: Expected,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+/// Answers are written as numbers or, for the ones that are not, strings.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Int(i64),
+    Text(String),
+}
+
+impl Value {
+    fn into_string(self) -> String {
+        match self {
+            Value::Int(n) => n.to_string(),
+            Value::Text(text) => text,
+        }
+    }
+}
+
+impl Fixture {
+    fn load(year: u16, day: u8, spec_path: &Path) -> Result<Self, String> {
+        let text = read(spec_path)?;
+        let spec: Spec = toml::from_str(&text)
+            .map_err(|err| format!("could not parse {}: {}", spec_path.display(), err))?;
+        let name = spec_path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        let input_path = match &spec.input {
+            Some(input) => spec_path.with_file_name(input),
+            None => spec_path.with_extension("txt"),
+        };
+        let answers = [(1, spec.answers.part1), (2, spec.answers.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| Some((part, answer?.into_string())))
+            .collect();
+        Ok(Fixture {
+            year,
+            day,
+            name,
+            input: read(&input_path)?,
+            params: spec.params.into_iter().collect(),
+            answers,
+            slow: spec.slow,
+        })
+    }
+
+    /// Solves the parts with an answer and compares them against it.
+    pub fn check(&self, day: &Day) -> Vec<(u8, Outcome)> {
+        let parts: Vec<u8> = self.answers.iter().map(|&(part, _)| part).collect();
+        let answers = day
+            .solve(&self.input, &self.params, &parts)
+            .unwrap_or_else(|failure| {
+                parts
+                    .iter()
+                    .map(|&part| (part, Err(failure.clone())))
+                    .collect()
+            });
+        answers
+            .into_iter()
+            .zip(&self.answers)
+            .map(|((part, answer), (_, expected))| {
+                let outcome = match answer {
+                    Ok(answer) if answer.to_string() == *expected => Outcome::Pass(answer),
+                    Ok(answer) => Outcome::Wrong {
+                        answer,
+                        expected: expected.clone(),
+                    },
+                    Err(failure) => Outcome::Failed {
+                        failure,
+                        expected: expected.clone(),
+                    },
+                };
+                (part, outcome)
+            })
+            .collect()
+    }
+}
+
+/// How a fixture's answer for one part compares to the expected one.
+pub enum Outcome {
+    Pass(Answer),
+    Wrong { answer: Answer, expected: String },
+    Failed { failure: Failure, expected: String },
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        matches!(self, Outcome::Pass(_))
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass(answer) => write!(f, "{}", answer),
+            Outcome::Wrong { answer, expected } => {
+                write!(f, "{} (expected {})", answer, expected)
+            }
+            Outcome::Failed { failure, expected } => {
+                write!(f, "{} (expected {})", failure, expected)
+            }
+        }
+    }
+}
+
+/// Loads every fixture in `dir` for the days `select` accepts, ordered by
+/// year, day and name. Directories that are not a year or a day are skipped.
+pub fn load(dir: &Path, select: impl Fn(u16, u8) -> bool) -> Result<Vec<Fixture>, String> {
+    let mut fixtures = Vec::new();
+    for (year, year_dir) in numbered(dir)? {
+        for (day, day_dir) in numbered(&year_dir)? {
+            if !select(year, day) {
+                continue;
+            }
+            let mut specs: Vec<PathBuf> = entries(&day_dir)?
+                .into_iter()
+                .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+                .collect();
+            specs.sort();
+            for spec in specs {
+                fixtures.push(Fixture::load(year, day, &spec)?);
+            }
+        }
+    }
+    Ok(fixtures)
+}
+
+/// The subdirectories of `dir` named by a number, in order.
+fn numbered<N: std::str::FromStr + Ord>(dir: &Path) -> Result<Vec<(N, PathBuf)>, String> {
+    let mut dirs: Vec<(N, PathBuf)> = entries(dir)?
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .filter_map(|path| Some((path.file_name()?.to_str()?.parse().ok()?, path)))
+        .collect();
+    dirs.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(dirs)
+}
+
+fn entries(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let read_err = |err| format!("could not read {}: {}", dir.display(), err);
+    fs::read_dir(dir)
+        .map_err(read_err)?
+        .map(|entry| entry.map(|entry| entry.path()).map_err(read_err))
+        .collect()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("could not read {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::DAYS;
+
+    use super::*;
+
+    /// Every committed fixture gives its expected answers. The slow ones are
+    /// left to `aoc fixtures` in a release build, as with `--skip-slow`.
+    #[test]
+    fn fixtures() {
+        let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"));
+        let fixtures = load(dir, |_, _| true).unwrap_or_else(|err| panic!("{}", err));
+        assert!(!fixtures.is_empty(), "no fixtures in {}", dir.display());
+
+        let mut failures = Vec::new();
+        for fixture in fixtures.iter().filter(|fixture| !fixture.slow) {
+            let Some(day) = DAYS
+                .iter()
+                .find(|d| d.year == fixture.year && d.day == fixture.day)
+            else {
+                failures.push(format!(
+                    "{} day {:02} {}: no such day",
+                    fixture.year, fixture.day, fixture.name
+                ));
+                continue;
+            };
+            for (part, outcome) in fixture.check(day) {
+                if !outcome.passed() {
+                    failures.push(format!(
+                        "{} day {:02} {} part {}: {}",
+                        fixture.year, fixture.day, fixture.name, part, outcome
+                    ));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn answers_are_numbers_or_strings() {
+        let spec: Spec =
+            toml::from_str("[params]\nwidth = 11\n\n[answers]\npart1 = 12\npart2 = \"co,de\"\n")
+                .unwrap();
+        assert_eq!(spec.params["width"], 11);
+        assert_eq!(spec.answers.part1.unwrap().into_string(), "12");
+        assert_eq!(spec.answers.part2.unwrap().into_string(), "co,de");
+        assert!(toml::from_str::<Spec>("[answers]\npart3 = 1\n").is_err());
+    }
+}
@@ -0,0 +1,500 @@
+mod answers;
+mod bench;
+mod days;
+mod fixtures;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_core::input::{Inputs, Source};
+use aoc_core::{AocError, Params};
+use aoc_vis::Player;
+use clap::{Parser, Subcommand};
+
+use answers::{Key, Store};
+use bench::{Baseline, Format};
+use days::{DAYS, Day, Failure};
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run every day, a whole year, or a single day.
+    Run {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Only run this part.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file instead, or from stdin if `-`.
+        /// Requires a single day.
+        #[arg(short, long, requires = "day")]
+        input: Option<String>,
+        /// Solve with a puzzle constant other than the real one, e.g.
+        /// `--param width=11` for an example. Requires a single day.
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param, requires = "day")]
+        params: Vec<(String, i64)>,
+        /// Watch the day's simulation before solving it. Requires a single
+        /// day.
+        #[arg(long, requires = "day")]
+        visualize: bool,
+        /// Frames a second to start the simulation at.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..), requires = "visualize")]
+        fps: u32,
+        /// Draw the day's simulation or solution to this file before solving
+        /// it, a PNG for still pictures, a GIF for animations or a DOT file
+        /// for graphs. Requires a single day.
+        #[arg(long, value_name = "PATH", requires = "day")]
+        export: Option<PathBuf>,
+        /// Directory holding the inputs as `<year>/<day>.txt`.
+        #[arg(long, env = "AOC_INPUTS", default_value = Inputs::DEFAULT_DIR)]
+        inputs: PathBuf,
+    },
+    /// Time parsing and both parts of every day, a whole year, or a single day.
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// How many times to run each day.
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        /// Save the results as JSON, to compare later runs against.
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare median timings against results saved with `--save`.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How many percent slower than the baseline counts as a regression.
+        #[arg(long, default_value_t = 10.0, requires = "baseline")]
+        threshold: f64,
+        /// Directory holding the inputs as `<year>/<day>.txt`.
+        #[arg(long, env = "AOC_INPUTS", default_value = Inputs::DEFAULT_DIR)]
+        inputs: PathBuf,
+    },
+    /// Run days on their real inputs and compare the answers with the ones
+    /// recorded in `answers.txt` in the inputs directory.
+    Verify {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Record new and changed answers.
+        #[arg(long)]
+        update: bool,
+        /// Directory holding the inputs as `<year>/<day>.txt`.
+        #[arg(long, env = "AOC_INPUTS", default_value = Inputs::DEFAULT_DIR)]
+        inputs: PathBuf,
+    },
+    /// Run days on the example fixtures and check their answers.
+    Fixtures {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Directory holding the fixtures as `<year>/<day>/<name>.toml`.
+        #[arg(long, default_value = fixtures::DEFAULT_DIR)]
+        dir: PathBuf,
+        /// Skip the fixtures marked as slow.
+        #[arg(long)]
+        skip_slow: bool,
+    },
+}
+
+fn parse_param(arg: &str) -> Result<(String, i64), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, found {:?}", arg))?;
+    let value = value
+        .parse()
+        .map_err(|_| format!("{:?} is not an integer", value))?;
+    Ok((name.to_string(), value))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            params,
+            visualize,
+            fps,
+            export,
+            inputs,
+        } => {
+            let source = input.as_deref().map_or(Source::Default, Source::from_arg);
+            let options = RunOptions {
+                part,
+                params: params.into_iter().collect(),
+                fps: visualize.then_some(fps),
+                export,
+            };
+            run(year, day, &options, &Inputs::new(inputs), &source)
+        }
+        Command::Bench {
+            year,
+            day,
+            iterations,
+            format,
+            save,
+            baseline,
+            threshold,
+            inputs,
+        } => {
+            let baseline = match baseline.as_deref().map(Baseline::load).transpose() {
+                Ok(baseline) => baseline,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let options = BenchOptions {
+                iterations: iterations as usize,
+                format,
+                save,
+                baseline,
+                threshold,
+            };
+            bench(year, day, &options, &Inputs::new(inputs))
+        }
+        Command::Verify {
+            year,
+            day,
+            update,
+            inputs,
+        } => verify(year, day, update, &Inputs::new(inputs)),
+        Command::Fixtures {
+            year,
+            day,
+            dir,
+            skip_slow,
+        } => check_fixtures(year, day, &dir, skip_slow),
+    }
+}
+
+fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Day> {
+    let days: Vec<&Day> = DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n))
+        .collect();
+    if days.is_empty() {
+        eprintln!("no solutions match");
+    }
+    days
+}
+
+struct RunOptions {
+    part: Option<u8>,
+    params: Params,
+    /// Play the simulation first, at this many frames a second.
+    fps: Option<u32>,
+    /// Export a picture to this path first.
+    export: Option<PathBuf>,
+}
+
+fn run(
+    year: Option<u16>,
+    day: Option<u8>,
+    options: &RunOptions,
+    inputs: &Inputs,
+    source: &Source,
+) -> ExitCode {
+    let days = select(year, day);
+    if days.is_empty() {
+        return ExitCode::FAILURE;
+    }
+
+    let params = &options.params;
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut ok = true;
+    for day in days {
+        println!("{} day {:02}", day.year, day.day);
+        let input = match inputs.load(day.year, day.day, source) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("  {}", err);
+                ok = false;
+                continue;
+            }
+        };
+        if let Some(fps) = options.fps
+            && let Err(err) = watch(day, &input, params, fps)
+        {
+            eprintln!("  {}", err);
+            ok = false;
+            continue;
+        }
+        if let Some(path) = &options.export {
+            match day.export(&input, params, path) {
+                Some(Ok(())) => println!("  Wrote {}", path.display()),
+                Some(Err(err)) => {
+                    eprintln!("  {}", err);
+                    ok = false;
+                    continue;
+                }
+                None => {
+                    eprintln!("  the day has no picture to export");
+                    ok = false;
+                    continue;
+                }
+            }
+        }
+        let answers = match day.solve(&input, params, &parts) {
+            Ok(answers) => answers,
+            Err(failure) => {
+                eprintln!("  could not parse the input: {}", failure);
+                ok = false;
+                continue;
+            }
+        };
+        for (part, answer) in answers {
+            match answer {
+                Ok(answer) => println!("  Part {}: {}", part, answer),
+                Err(failure @ Failure::Error(AocError::Unsolved(_))) => {
+                    println!("  Part {}: {}", part, failure)
+                }
+                Err(failure) => {
+                    println!("  Part {}: {}", part, failure);
+                    ok = false;
+                }
+            }
+        }
+    }
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Plays the day's simulation of `input`, restoring the terminal before
+/// returning.
+fn watch(day: &Day, input: &str, params: &Params, fps: u32) -> Result<(), String> {
+    if !day.has_visualization() {
+        return Err("the day has no visualization".to_string());
+    }
+    let mut player =
+        Player::new(fps).map_err(|err| format!("could not set up the terminal: {}", err))?;
+    let played = day.visualize(input, params, &mut player);
+    player.finish();
+    drop(player);
+    match played {
+        Some(Err(failure)) => Err(format!("could not visualize the input: {}", failure)),
+        _ => Ok(()),
+    }
+}
+
+struct BenchOptions {
+    iterations: usize,
+    format: Format,
+    save: Option<PathBuf>,
+    baseline: Option<Baseline>,
+    threshold: f64,
+}
+
+fn bench(year: Option<u16>, day: Option<u8>, options: &BenchOptions, inputs: &Inputs) -> ExitCode {
+    let days = select(year, day);
+    if days.is_empty() {
+        return ExitCode::FAILURE;
+    }
+
+    let table = matches!(options.format, Format::Table);
+    let mut records = Vec::new();
+    let mut ok = true;
+    let mut regressions = 0;
+    for day in days {
+        if table {
+            println!("{} day {:02}", day.year, day.day);
+        }
+        let input = match inputs.load(day.year, day.day, &Source::Default) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("  {}", err);
+                ok = false;
+                continue;
+            }
+        };
+        let timings = day.bench(&input, options.iterations);
+        for (phase, record) in bench::summarise(day, &timings) {
+            let change = record
+                .as_ref()
+                .zip(options.baseline.as_ref())
+                .and_then(|(record, baseline)| baseline.change(record));
+            if change.is_some_and(|change| change > options.threshold) {
+                regressions += 1;
+            }
+            if table {
+                bench::print_row(phase, record.as_ref(), change, options.threshold);
+            }
+            match record {
+                Some(record) => records.push(record),
+                None => ok = false,
+            }
+        }
+    }
+
+    match options.format {
+        Format::Table => {}
+        Format::Json => println!("{}", bench::to_json(&records)),
+        Format::Csv => print!("{}", bench::to_csv(&records)),
+    }
+    if let Some(path) = &options.save
+        && let Err(err) = fs::write(path, bench::to_json(&records))
+    {
+        eprintln!("could not write {}: {}", path.display(), err);
+        ok = false;
+    }
+    if regressions > 0 {
+        eprintln!(
+            "{} phases are more than {}% slower than the baseline",
+            regressions, options.threshold
+        );
+        ok = false;
+    }
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn verify(year: Option<u16>, day: Option<u8>, update: bool, inputs: &Inputs) -> ExitCode {
+    let days = select(year, day);
+    if days.is_empty() {
+        return ExitCode::FAILURE;
+    }
+    let mut store = match Store::load(inputs) {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut unchanged, mut changed, mut new, mut unsolved, mut failed) = (0, 0, 0, 0, 0);
+    let mut missing = 0;
+    for day in days {
+        println!("{} day {:02}", day.year, day.day);
+        let input = match inputs.load(day.year, day.day, &Source::Default) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("  {}", err);
+                missing += 1;
+                continue;
+            }
+        };
+        let hash = answers::hash(&input);
+        let answers = day
+            .solve(&input, &Params::default(), &[1, 2])
+            .unwrap_or_else(|failure| vec![(1, Err(failure.clone())), (2, Err(failure))]);
+        for (part, answer) in answers {
+            let key = Key {
+                year: day.year,
+                day: day.day,
+                part,
+                hash,
+            };
+            let known = store.get(&key).map(str::to_string);
+            match (answer, known) {
+                (Ok(answer), Some(known)) if answer.to_string() == known => {
+                    println!("  Part {}: {}", part, answer);
+                    unchanged += 1;
+                }
+                (Ok(answer), Some(known)) => {
+                    println!("  Part {}: {} (changed, was {})", part, answer, known);
+                    changed += 1;
+                    if update {
+                        store.insert(key, answer.to_string());
+                    }
+                }
+                (Ok(answer), None) => {
+                    println!("  Part {}: {} (new)", part, answer);
+                    new += 1;
+                    if update {
+                        store.insert(key, answer.to_string());
+                    }
+                }
+                (Err(failure), Some(known)) => {
+                    println!("  Part {}: {} (was {})", part, failure, known);
+                    changed += 1;
+                }
+                (Err(failure @ Failure::Error(AocError::Unsolved(_))), None) => {
+                    println!("  Part {}: {}", part, failure);
+                    unsolved += 1;
+                }
+                (Err(failure), None) => {
+                    println!("  Part {}: {}", part, failure);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} unchanged, {} changed, {} new, {} unsolved, {} failed, {} missing inputs",
+        unchanged, changed, new, unsolved, failed, missing
+    );
+    if update {
+        if let Err(err) = store.save() {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+        println!("recorded the answers in {}", store.path().display());
+    }
+    if changed > 0 || missing > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn check_fixtures(year: Option<u16>, day: Option<u8>, dir: &Path, skip_slow: bool) -> ExitCode {
+    let days = select(year, day);
+    if days.is_empty() {
+        return ExitCode::FAILURE;
+    }
+    let fixtures = match fixtures::load(dir, |y, d| {
+        days.iter().any(|day| day.year == y && day.day == d)
+    }) {
+        Ok(fixtures) => fixtures,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed) = (0, 0);
+    for fixture in fixtures
+        .iter()
+        .filter(|fixture| !(skip_slow && fixture.slow))
+    {
+        println!("{} day {:02} {}", fixture.year, fixture.day, fixture.name);
+        let day = days
+            .iter()
+            .find(|d| d.year == fixture.year && d.day == fixture.day)
+            .expect("fixtures are only loaded for selected days");
+        for (part, outcome) in fixture.check(day) {
+            if outcome.passed() {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+            println!("  Part {}: {}", part, outcome);
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}